@@ -0,0 +1,60 @@
+//! One benchmark group per day of every year, timing parsing and each part separately.
+//! Inputs are the worked examples in `fixtures/`, so no private puzzle input is needed.
+
+use std::hint::black_box;
+
+use aoc::exec::Context;
+use aoc::solution::{Day, Overrides, YEARS};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn fixture(year: u16, day: u8) -> String {
+    let path = format!(
+        "{}/fixtures/{year}/day{day}.txt",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("could not read {path}: {e}"))
+}
+
+/// The worked examples are smaller than the real inputs, so some days need
+/// parameters to match.
+fn example_params(year: u16, day: u8) -> Overrides {
+    let params: &[&str] = match (year, day) {
+        (2024, 14) => &["width=11", "height=7"],
+        (2024, 18) => &["width=7", "corrupted=12"],
+        _ => &[],
+    };
+    Overrides::parse(params.iter().copied()).unwrap()
+}
+
+/// Benchmarks one day's parser, then each of its parts on the parsed input.
+fn bench_day(c: &mut Criterion, year: u16, day: &dyn Day, input: &str, params: &Overrides) {
+    let mut group = c.benchmark_group(format!("{year}/day{}", day.day()));
+    group.bench_function("parse", |b| b.iter(|| day.parse(black_box(input)).unwrap()));
+    let parsed = day.parse(input).unwrap();
+    let ctx = Context::default();
+    for part in [1, 2] {
+        if parsed.solve(part, params, &ctx).unwrap().is_some() {
+            group.bench_function(format!("part{part}"), |b| {
+                b.iter(|| parsed.solve(part, params, &ctx))
+            });
+        }
+    }
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    for year in YEARS {
+        let y = year.year;
+        for &day in year.days {
+            let n = day.day();
+            bench_day(c, y, day, &fixture(y, n), &example_params(y, n));
+        }
+    }
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = days
+}
+criterion_main!(benches);
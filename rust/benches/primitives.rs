@@ -0,0 +1,52 @@
+//! Micro-benchmarks for the shared utilities, and the hottest functions in the slowest days.
+
+use std::hint::black_box;
+
+use aoc::y2024::{day16, day17};
+use aoc::{exec::Context, grid::Grid, point::Point};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn fixture(day: u8) -> String {
+    let path = format!("{}/fixtures/2024/day{day}.txt", env!("CARGO_MANIFEST_DIR"));
+    std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("could not read {path}: {e}"))
+}
+
+fn grid_get(c: &mut Criterion) {
+    // Same size as most puzzle grids. Includes a border of out-of-bounds points.
+    let grid = Grid::new(141, 141, 1u8);
+    c.bench_function("Grid::get", |b| {
+        b.iter(|| {
+            let mut sum = 0usize;
+            for y in -1..=141 {
+                for x in -1..=141 {
+                    sum += usize::from(grid.get(black_box(Point { x, y })).copied().unwrap_or(0));
+                }
+            }
+            sum
+        })
+    });
+}
+
+fn point_cardinal(c: &mut Criterion) {
+    c.bench_function("Point::cardinal", |b| {
+        b.iter(|| black_box(Point { x: 3, y: 4 }).cardinal())
+    });
+}
+
+fn day16_dijkstra(c: &mut Criterion) {
+    let input = day16::parse(&fixture(16)).unwrap();
+    c.bench_function("day16::dijkstra", |b| {
+        b.iter(|| day16::dijkstra(&input, input.start(), input.end()).unwrap())
+    });
+}
+
+fn day17_run(c: &mut Criterion) {
+    let input = day17::parse(&fixture(17)).unwrap();
+    let ctx = Context::default();
+    c.bench_function("day17::run", |b| {
+        b.iter(|| day17::run(input.registers.clone(), &input.program, false, &ctx))
+    });
+}
+
+criterion_group!(benches, grid_get, point_cardinal, day16_dijkstra, day17_run);
+criterion_main!(benches);
@@ -0,0 +1,38 @@
+//! Fingerprints the crate's sources, so the answer cache (src/cache.rs) can tell
+//! when a solution might have changed.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Everything an answer could depend on, relative to the crate root.
+const SOURCES: [&str; 3] = ["src", "derive/src", "Cargo.toml"];
+
+fn main() {
+    let mut files = Vec::new();
+    for source in SOURCES {
+        println!("cargo:rerun-if-changed={source}");
+        collect(Path::new(source), &mut files);
+    }
+    files.sort();
+
+    // 64-bit FNV-1a, like `inputs::hash`, over each file's path and contents.
+    let mut hash = 0xcbf29ce484222325u64;
+    for file in &files {
+        let contents = fs::read(file).unwrap_or_else(|e| panic!("{}: {e}", file.display()));
+        for byte in file.to_string_lossy().bytes().chain([0]).chain(contents) {
+            hash = (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3);
+        }
+    }
+    println!("cargo:rustc-env=AOC_SOURCE_HASH={hash:016x}");
+}
+
+fn collect(path: &Path, files: &mut Vec<PathBuf>) {
+    if path.is_dir() {
+        let entries = fs::read_dir(path).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+        for entry in entries {
+            collect(&entry.expect("reading a source directory").path(), files);
+        }
+    } else {
+        files.push(path.to_owned());
+    }
+}
@@ -0,0 +1,98 @@
+//! Derive macros for the `aoc` crate, which can't define its own.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, LitChar};
+
+/// Derives `aoc::grid::GridCell`, `TryFrom<char>` and `Display` for an enum
+/// of cells in a grid, each written as the character in its `#[cell]` attribute:
+///
+/// ```ignore
+/// #[derive(GridCell)]
+/// enum Cell {
+///     #[cell('#')]
+///     Wall,
+///     #[cell('.')]
+///     Empty,
+/// }
+/// ```
+#[proc_macro_derive(GridCell, attributes(cell))]
+pub fn derive_grid_cell(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    grid_cell(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn grid_cell(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "GridCell can only be derived for enums",
+        ));
+    };
+    let mut variants = Vec::new();
+    let mut chars: Vec<LitChar> = Vec::new();
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "GridCell variants can't have fields",
+            ));
+        }
+        let mut ch = None;
+        for attr in variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("cell"))
+        {
+            if ch.is_some() {
+                return Err(syn::Error::new_spanned(attr, "more than one #[cell]"));
+            }
+            ch = Some(attr.parse_args::<LitChar>()?);
+        }
+        let ch = ch.ok_or_else(|| {
+            syn::Error::new_spanned(variant, "missing #[cell('c')] with the variant's character")
+        })?;
+        if chars.iter().any(|other| other.value() == ch.value()) {
+            return Err(syn::Error::new_spanned(
+                ch,
+                "another variant is already written as this",
+            ));
+        }
+        variants.push(&variant.ident);
+        chars.push(ch);
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::aoc::grid::GridCell for #name #ty_generics #where_clause {
+            const CHARS: &'static [char] = &[#(#chars),*];
+        }
+
+        impl #impl_generics ::core::convert::TryFrom<char> for #name #ty_generics #where_clause {
+            type Error = ::aoc::grid::UnknownCell;
+
+            fn try_from(ch: char) -> ::core::result::Result<Self, Self::Error> {
+                match ch {
+                    #(#chars => ::core::result::Result::Ok(Self::#variants),)*
+                    _ => ::core::result::Result::Err(::aoc::grid::UnknownCell {
+                        found: ch,
+                        expected: <Self as ::aoc::grid::GridCell>::CHARS,
+                    }),
+                }
+            }
+        }
+
+        impl #impl_generics ::core::fmt::Display for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                let ch = match self {
+                    #(Self::#variants => #chars,)*
+                };
+                ::core::fmt::Display::fmt(&ch, f)
+            }
+        }
+    })
+}
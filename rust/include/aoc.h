@@ -0,0 +1,79 @@
+#ifndef AOC_H
+#define AOC_H
+
+/* Generated by cbindgen from src/ffi.rs. Don't edit it by hand: run `just header`. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * The answer was written to the output buffer.
+ */
+#define AOC_OK 0
+
+/**
+ * There's no solution for that day and part.
+ */
+#define AOC_NO_SOLUTION 1
+
+/**
+ * The input isn't UTF-8, or didn't parse. The reason was written to the output buffer.
+ */
+#define AOC_BAD_INPUT 2
+
+/**
+ * The solution failed. The reason was written to the output buffer.
+ */
+#define AOC_FAILED 3
+
+/**
+ * The output buffer is too small. `out_len` was set to the size it needs to be.
+ */
+#define AOC_BUFFER_TOO_SMALL 4
+
+/**
+ * One of the pointers was null.
+ */
+#define AOC_NULL_POINTER 5
+
+/**
+ * Solves one part of one day of the latest year, with the default parameters,
+ * like [`aoc_solve_year`].
+ *
+ * # Safety
+ *
+ * As for [`aoc_solve_year`].
+ */
+int32_t aoc_solve(uint8_t day,
+                  uint8_t part,
+                  const uint8_t *input_ptr,
+                  size_t input_len,
+                  uint8_t *out_buf,
+                  size_t *out_len);
+
+/**
+ * Solves one part of one day of the given year, with the default parameters, writing the answer
+ * (or why there isn't one) to `out_buf` as UTF-8 text, without a terminating NUL.
+ *
+ * `*out_len` is the size of `out_buf` on entry, and the length of what was written
+ * on exit. If it didn't fit, nothing is written, and `*out_len` is set to the size needed.
+ *
+ * Returns one of the `AOC_` status codes.
+ *
+ * # Safety
+ *
+ * `input_ptr` must point to `input_len` readable bytes, `out_len` to a writable
+ * `usize`, and `out_buf` to `*out_len` writable bytes.
+ */
+int32_t aoc_solve_year(uint16_t year,
+                       uint8_t day,
+                       uint8_t part,
+                       const uint8_t *input_ptr,
+                       size_t input_len,
+                       uint8_t *out_buf,
+                       size_t *out_len);
+
+#endif  /* AOC_H */
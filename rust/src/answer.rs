@@ -0,0 +1,97 @@
+//! The answer to one part of a puzzle.
+
+use std::fmt;
+
+use crate::point::Point;
+
+/// Every solution returns one of these, so that the answer is always printed
+/// in the form Advent of Code expects to have pasted into it.
+///
+/// [`Display`](fmt::Display) gives that submission format,
+/// [`Debug`](fmt::Debug) says what kind of answer it is too.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+    /// Submitted as `x,y`.
+    Coord(Point),
+    /// Submitted as its items, separated by commas.
+    List(Vec<Answer>),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            Answer::Coord(p) => write!(f, "{},{}", p.x, p.y),
+            Answer::List(items) => {
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Int(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_owned())
+    }
+}
+
+impl From<Point> for Answer {
+    fn from(p: Point) -> Self {
+        Answer::Coord(p)
+    }
+}
+
+impl<T: Into<Answer>> From<Vec<T>> for Answer {
+    fn from(items: Vec<T>) -> Self {
+        Answer::List(items.into_iter().map(Into::into).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_submission_format() {
+        assert_eq!(
+            Answer::from(80882098756071u64).to_string(),
+            "80882098756071"
+        );
+        assert_eq!(Answer::from(Point { x: 6, y: 1 }).to_string(), "6,1");
+        assert_eq!(Answer::from(vec![4u64, 6, 3]).to_string(), "4,6,3");
+        assert_eq!(Answer::from(Vec::<u64>::new()).to_string(), "");
+        assert_eq!(
+            format!("{:?}", Answer::from(Point { x: 6, y: 1 })),
+            "Coord((6,1))"
+        );
+    }
+}
@@ -0,0 +1,162 @@
+//! Known-correct answers, checked in as `answers.toml`, so that every
+//! solution can be checked against its real input at once.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Deserializer};
+
+/// Where the answers live, relative to the crate root.
+pub const PATH: &str = "answers.toml";
+
+#[derive(Debug, Default, Deserialize)]
+pub struct Answers {
+    #[serde(default, rename = "answer")]
+    entries: Vec<Entry>,
+}
+
+/// The correct answer to one part of one day.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// Written as a TOML integer or string, but always compared as text.
+    #[serde(deserialize_with = "int_or_string")]
+    pub answer: String,
+    /// If given, this answer only applies to the input with this hash
+    /// (see [`crate::inputs::hash`]). Otherwise it applies to any input.
+    pub input_hash: Option<String>,
+}
+
+/// How an answer compared to the known-correct one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// There's no known answer for this input.
+    Unknown,
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(text)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        let text = std::fs::read_to_string(path).map_err(|e| AnswersError {
+            path: path.to_owned(),
+            reason: e.to_string(),
+        })?;
+        Self::parse(&text).map_err(|e| AnswersError {
+            path: path.to_owned(),
+            reason: e.to_string(),
+        })
+    }
+
+    /// The correct answer for this input, if known.
+    /// Answers recorded for this exact input take priority over general ones.
+    pub fn get(&self, year: u16, day: u8, part: u8, input_hash: &str) -> Option<&str> {
+        let mut general = None;
+        for entry in self
+            .entries
+            .iter()
+            .filter(|e| e.year == year && e.day == day && e.part == part)
+        {
+            match &entry.input_hash {
+                Some(hash) if hash == input_hash => return Some(&entry.answer),
+                Some(_) => {}
+                None => general = general.or(Some(entry.answer.as_str())),
+            }
+        }
+        general
+    }
+
+    pub fn check(&self, year: u16, day: u8, part: u8, input_hash: &str, answer: &str) -> Verdict {
+        match self.get(year, day, part, input_hash) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_owned(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+fn int_or_string<'de, D: Deserializer<'de>>(d: D) -> Result<String, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Value {
+        Int(i64),
+        Text(String),
+    }
+    Ok(match Value::deserialize(d)? {
+        Value::Int(n) => n.to_string(),
+        Value::Text(s) => s,
+    })
+}
+
+#[derive(Debug)]
+pub struct AnswersError {
+    pub path: PathBuf,
+    pub reason: String,
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "could not load {}: {}", self.path.display(), self.reason)
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = r#"
+[[answer]]
+year = 2024
+day = 18
+part = 2
+answer = "6,1"
+
+[[answer]]
+year = 2024
+day = 18
+part = 2
+answer = "20,12"
+input_hash = "abc"
+
+[[answer]]
+year = 2024
+day = 3
+part = 1
+answer = 153469856
+
+[[answer]]
+year = 2025
+day = 3
+part = 1
+answer = 42
+"#;
+
+    #[test]
+    fn test_lookup() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.get(2024, 3, 1, "any"), Some("153469856"));
+        assert_eq!(answers.get(2025, 3, 1, "any"), Some("42"));
+        assert_eq!(answers.get(2024, 3, 2, "any"), None);
+        assert_eq!(answers.get(2024, 18, 2, "abc"), Some("20,12"));
+        assert_eq!(answers.get(2024, 18, 2, "def"), Some("6,1"));
+        assert_eq!(
+            answers.check(2024, 18, 2, "abc", "6,1"),
+            Verdict::Fail {
+                expected: "20,12".to_owned()
+            }
+        );
+    }
+}
@@ -0,0 +1,627 @@
+use std::cell::RefCell;
+use std::error::Error;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use aoc::answers::{Answers, Verdict};
+use aoc::cache::{self, Cache};
+use aoc::exec::{Budget, Context, Exec, Threads};
+use aoc::memory::Stats;
+use aoc::solution::{self, Day, Overrides, SolveError, Year};
+use aoc::*;
+use clap::{Args, Parser, Subcommand};
+
+#[cfg(feature = "count-allocs")]
+#[global_allocator]
+static ALLOC: aoc::memory::Counting = aoc::memory::Counting::new();
+
+/// Runs `f`, timing it and counting what it allocated (if allocations are being counted).
+fn measure<R>(f: impl FnOnce() -> R) -> (R, Duration, Option<Stats>) {
+    let start = Instant::now();
+    #[cfg(feature = "count-allocs")]
+    let (result, stats) = {
+        let (result, stats) = ALLOC.measure(f);
+        (result, Some(stats))
+    };
+    #[cfg(not(feature = "count-allocs"))]
+    let (result, stats) = (f(), None);
+    (result, start.elapsed(), stats)
+}
+
+/// Runs `f`, turning a panic into an error, so one broken day doesn't stop the rest running.
+fn catch_panic<R>(f: impl FnOnce() -> R) -> Result<R, Box<dyn Error>> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|_| "the solution panicked".into())
+}
+
+#[derive(Parser)]
+#[command(about = "Run Advent of Code solutions without cargo-aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run solutions, printing their answers and how long they took.
+    Run {
+        /// The year of the puzzles. The latest year with solutions if not given.
+        #[arg(long)]
+        year: Option<u16>,
+        /// Only run this day. Runs every day if not given.
+        #[arg(long)]
+        day: Option<u8>,
+        /// Only run this part. Runs both parts if not given.
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Read the puzzle input from this file (or `-` for stdin), instead of
+        /// `input/YEAR/dayN.txt`. The input directory can also be set with `AOC_INPUT_DIR`.
+        #[arg(long, requires = "day")]
+        input: Option<PathBuf>,
+        /// Override one of the day's parameters, e.g. `--param width=7` to solve
+        /// the worked example on a 7x7 grid. Can be given more than once.
+        #[arg(long = "param", value_name = "KEY=VALUE", requires = "day")]
+        params: Vec<String>,
+        #[command(flatten)]
+        exec: ExecArgs,
+        #[command(flatten)]
+        cache: CacheArgs,
+    },
+    /// Check every solution's answer for its real input against the known-correct answers.
+    Verify {
+        /// The year of the puzzles. The latest year with solutions if not given.
+        #[arg(long)]
+        year: Option<u16>,
+        /// Only verify this day. Verifies every day if not given.
+        #[arg(long)]
+        day: Option<u8>,
+        /// File of known-correct answers.
+        #[arg(long, default_value = answers::PATH)]
+        answers: PathBuf,
+        #[command(flatten)]
+        exec: ExecArgs,
+        #[command(flatten)]
+        cache: CacheArgs,
+    },
+    /// Run solutions, and write a report of every part's answer, timings and memory use.
+    /// Prints a Markdown table if no output file is given.
+    /// Always solves everything, so every number in it is from this run.
+    Report {
+        /// The year of the puzzles. The latest year with solutions if not given.
+        #[arg(long)]
+        year: Option<u16>,
+        /// Only report on this day. Reports on every day if not given.
+        #[arg(long)]
+        day: Option<u8>,
+        /// Write the report as JSON to this file.
+        #[arg(long, value_name = "PATH")]
+        json: Option<PathBuf>,
+        /// Write the report as a Markdown table to this file.
+        #[arg(long, value_name = "PATH")]
+        markdown: Option<PathBuf>,
+        #[command(flatten)]
+        exec: ExecArgs,
+    },
+    /// Check puzzle inputs' shapes before solving them, reporting every problem and where it is.
+    Lint {
+        /// The year of the puzzles. The latest year with solutions if not given.
+        #[arg(long)]
+        year: Option<u16>,
+        /// Only check this day's input. Checks every day's if not given.
+        #[arg(long)]
+        day: Option<u8>,
+        /// Check this file (or `-` for stdin), instead of `input/YEAR/dayN.txt`.
+        #[arg(long, requires = "day")]
+        input: Option<PathBuf>,
+    },
+    /// Answer requests like `{"day":12,"part":2,"input":"..."}` from stdin, one per line,
+    /// writing each answer (or error) and its timings to stdout as a line of JSON.
+    /// Keeps each day's last parsed input, so re-solving it is quick.
+    Serve {
+        #[command(flatten)]
+        exec: ExecArgs,
+    },
+    /// Add a new day: its module, an example input file and a placeholder answer,
+    /// and the year's module if it's the year's first day.
+    /// Run this from the crate root.
+    Scaffold {
+        /// The year of the puzzle. The latest year with solutions if not given.
+        #[arg(long)]
+        year: Option<u16>,
+        #[arg(long)]
+        day: u8,
+    },
+}
+
+#[derive(Args)]
+struct ExecArgs {
+    /// Give up on each part after this many seconds.
+    #[arg(long, value_name = "SECONDS")]
+    timeout: Option<f64>,
+    /// Give up on each part after this many iterations of its slowest loop.
+    #[arg(long)]
+    max_iterations: Option<u64>,
+    /// How many threads solutions can use. 1 runs them sequentially, without rayon.
+    /// Uses every core if not given.
+    #[arg(long)]
+    threads: Option<usize>,
+}
+
+#[derive(Args)]
+struct CacheArgs {
+    /// Solve everything, without reading or writing the answer cache.
+    #[arg(long, conflicts_with = "refresh_cache")]
+    no_cache: bool,
+    /// Solve everything, replacing the answer cache's answers with the new ones.
+    #[arg(long)]
+    refresh_cache: bool,
+}
+
+/// How to run each part.
+struct Settings {
+    budget: Budget,
+    exec: Exec,
+    /// Answers from earlier runs. Only used when solving with the default parameters.
+    cache: Option<RefCell<Cache>>,
+    /// Solve everything anyway, and update the cache.
+    refresh_cache: bool,
+}
+
+impl ExecArgs {
+    /// Without `cache`, everything is solved and nothing is cached.
+    fn settings(&self, cache: Option<&CacheArgs>) -> Settings {
+        Settings {
+            budget: self.budget(),
+            exec: self.exec(),
+            cache: cache.and_then(CacheArgs::open).map(RefCell::new),
+            refresh_cache: cache.is_some_and(|cache| cache.refresh_cache),
+        }
+    }
+
+    fn budget(&self) -> Budget {
+        Budget {
+            time: self.timeout.map(Duration::from_secs_f64),
+            iterations: self.max_iterations,
+        }
+    }
+
+    fn exec(&self) -> Exec {
+        let threads = self.threads.map_or(Threads::Auto, Threads::count);
+        Exec::new(threads).unwrap_or_else(|e| {
+            eprintln!("Could not start {threads:?} threads: {e}");
+            std::process::exit(1);
+        })
+    }
+}
+
+impl CacheArgs {
+    fn open(&self) -> Option<Cache> {
+        if self.no_cache {
+            return None;
+        }
+        let path = Path::new(cache::PATH);
+        Some(Cache::load(path).unwrap_or_else(|e| {
+            eprintln!("Ignoring {e}");
+            Cache::empty(path)
+        }))
+    }
+}
+
+impl Settings {
+    /// Every part gets a fresh budget, but they share threads.
+    fn context(&self) -> Context {
+        Context::new(self.budget, self.exec.clone())
+    }
+
+    /// Every part's outcome from the cache, if they're all in it.
+    fn cached(&self, year: u16, day: u8, input_hash: &str, parts: &[u8]) -> Option<Vec<Outcome>> {
+        if self.refresh_cache {
+            return None;
+        }
+        let cache = self.cache.as_ref()?.borrow();
+        let mut outcomes = Vec::new();
+        for &part in parts {
+            let entry = cache.get(&cache_key(year, day, part, input_hash))?;
+            if let Some(answer) = &entry.answer {
+                outcomes.push(Outcome {
+                    part,
+                    answer: Ok(answer.clone()),
+                    parse_time: entry.parse_time,
+                    solve_time: entry.solve_time,
+                    parse_allocs: None,
+                    solve_allocs: None,
+                    cached: true,
+                });
+            }
+        }
+        Some(outcomes)
+    }
+
+    fn remember(&self, key: cache::Key, entry: cache::Entry) {
+        if let Some(cache) = &self.cache {
+            cache.borrow_mut().insert(key, entry);
+        }
+    }
+
+    fn save_cache(&self) {
+        if let Some(Err(e)) = self.cache.as_ref().map(|cache| cache.borrow().save()) {
+            eprintln!("Could not save {e}");
+        }
+    }
+}
+
+fn cache_key(year: u16, day: u8, part: u8, input_hash: &str) -> cache::Key {
+    cache::Key {
+        year,
+        day,
+        part,
+        input_hash: input_hash.to_owned(),
+    }
+}
+
+/// The answer to one part of a puzzle (or why there isn't one),
+/// how long it took, and how much memory it allocated.
+struct Outcome {
+    part: u8,
+    answer: Result<String, SolveError>,
+    parse_time: Duration,
+    solve_time: Duration,
+    parse_allocs: Option<Stats>,
+    solve_allocs: Option<Stats>,
+    /// Whether this was found in the answer cache, rather than solved just now.
+    cached: bool,
+}
+
+/// Parses the input once, then solves the given part (or every part the day has),
+/// measuring each step separately. A panic in either step is an error for the whole day.
+/// Skips all that if the cache already has every answer.
+fn solve(
+    year: u16,
+    day: &dyn Day,
+    input: &str,
+    part: Option<u8>,
+    overrides: &Overrides,
+    settings: &Settings,
+) -> Result<Vec<Outcome>, Box<dyn Error>> {
+    let parts = part.map_or(vec![1, 2], |part| vec![part]);
+    // Answers for other parameters aren't cached.
+    let input_hash = overrides.is_empty().then(|| inputs::hash(input));
+    if let Some(outcomes) = input_hash
+        .as_ref()
+        .and_then(|hash| settings.cached(year, day.day(), hash, &parts))
+    {
+        return Ok(outcomes);
+    }
+    let (parsed, parse_time, parse_allocs) = measure(|| catch_panic(|| day.parse(input)));
+    let parsed = parsed??;
+    let mut outcomes = Vec::new();
+    for part in parts {
+        let ctx = settings.context();
+        let (answer, solve_time, solve_allocs) =
+            measure(|| catch_panic(|| parsed.solve(part, overrides, &ctx)));
+        let answer = answer?;
+        let answer = answer.map(|answer| answer.map(|answer| answer.to_string()));
+        if let (Some(hash), Ok(answer)) = (&input_hash, &answer) {
+            let entry = cache::Entry {
+                answer: answer.clone(),
+                parse_time,
+                solve_time,
+            };
+            settings.remember(cache_key(year, day.day(), part, hash), entry);
+        }
+        let answer = match answer {
+            Ok(None) => continue,
+            Ok(Some(answer)) => Ok(answer),
+            Err(e) => Err(e),
+        };
+        outcomes.push(Outcome {
+            part,
+            answer,
+            parse_time,
+            solve_time,
+            parse_allocs,
+            solve_allocs,
+            cached: false,
+        });
+    }
+    Ok(outcomes)
+}
+
+fn main() {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+            params,
+            exec,
+            cache,
+        } => {
+            let overrides =
+                Overrides::parse(params.iter().map(String::as_str)).unwrap_or_else(|e| {
+                    eprintln!("{e}");
+                    std::process::exit(1);
+                });
+            run(
+                select(year, day),
+                part,
+                input,
+                &overrides,
+                &exec.settings(Some(&cache)),
+            )
+        }
+        Command::Verify {
+            year,
+            day,
+            answers,
+            exec,
+            cache,
+        } => verify(select(year, day), &answers, &exec.settings(Some(&cache))),
+        Command::Report {
+            year,
+            day,
+            json,
+            markdown,
+            exec,
+        } => report(select(year, day), json, markdown, &exec.settings(None)),
+        Command::Lint { year, day, input } => lint(select(year, day), input),
+        Command::Serve { exec } => {
+            let mut server = serve::Server::new(exec.budget(), exec.exec());
+            if let Err(e) = server.run(std::io::stdin().lock(), std::io::stdout().lock()) {
+                eprintln!("{e}");
+                std::process::exit(1);
+            }
+        }
+        Command::Scaffold { year, day } => {
+            let year = year.unwrap_or(solution::latest().year);
+            match scaffold::scaffold(Path::new("."), year, day) {
+                Ok(changed) => {
+                    for path in changed {
+                        println!("Wrote {}", path.display());
+                    }
+                }
+                Err(e) => {
+                    eprintln!("Could not add day {day} of {year}: {e}");
+                    std::process::exit(1);
+                }
+            }
+        }
+    }
+}
+
+/// The given year's solutions, or the latest year's if not given.
+struct Selected {
+    year: u16,
+    days: Vec<&'static dyn Day>,
+}
+
+/// The given day's solution, or every day's if not given.
+fn select(year: Option<u16>, day: Option<u8>) -> Selected {
+    let Some(&Year { year, days }) = year.map_or(Some(solution::latest()), solution::year) else {
+        eprintln!("No solutions for that year");
+        std::process::exit(1);
+    };
+    let days: Vec<_> = days
+        .iter()
+        .copied()
+        .filter(|d| day.is_none_or(|day| d.day() == day))
+        .collect();
+    if days.is_empty() {
+        eprintln!("No solutions match that day");
+        std::process::exit(1);
+    }
+    Selected { year, days }
+}
+
+fn run(
+    selected: Selected,
+    part: Option<u8>,
+    input: Option<PathBuf>,
+    overrides: &Overrides,
+    settings: &Settings,
+) {
+    let year = selected.year;
+    let mut failed = false;
+    for day in selected.days {
+        let path = input
+            .clone()
+            .unwrap_or_else(|| inputs::path(year, day.day()));
+        let outcomes = inputs::read(&path)
+            .map_err(Box::<dyn Error>::from)
+            .and_then(|contents| solve(year, day, &contents, part, overrides, settings));
+        let outcomes = match outcomes {
+            Ok(outcomes) => outcomes,
+            Err(e) => {
+                eprintln!("Day {}: FAILED: {e}", day.day());
+                failed = true;
+                continue;
+            }
+        };
+        if let (Some(part), true) = (part, outcomes.is_empty()) {
+            eprintln!("Day {} has no part {part}", day.day());
+            failed = true;
+        }
+        for outcome in outcomes {
+            match outcome.answer {
+                Ok(answer) => {
+                    println!(
+                        "Day {} - Part {}: {answer}\n\tparse: {:?}, solve: {:?}{}",
+                        day.day(),
+                        outcome.part,
+                        outcome.parse_time,
+                        outcome.solve_time,
+                        if outcome.cached { " (cached)" } else { "" }
+                    );
+                    if let (Some(parse), Some(solve)) = (outcome.parse_allocs, outcome.solve_allocs)
+                    {
+                        println!("\tparse memory: {parse}\n\tsolve memory: {solve}");
+                    }
+                }
+                Err(e) => {
+                    eprintln!("Day {} - Part {}: FAILED: {e}", day.day(), outcome.part);
+                    failed = true;
+                }
+            }
+        }
+    }
+    settings.save_cache();
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+fn verify(selected: Selected, answers: &Path, settings: &Settings) {
+    let answers = Answers::load(answers).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        std::process::exit(1);
+    });
+    let year = selected.year;
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for day in selected.days {
+        let input = match inputs::load(year, day.day()) {
+            Ok(input) => input,
+            Err(e) => {
+                println!("Day {}: missing ({e})", day.day());
+                missing += 1;
+                continue;
+            }
+        };
+        let outcomes = match solve(year, day, &input, None, &Overrides::default(), settings) {
+            Ok(outcomes) => outcomes,
+            Err(e) => {
+                println!("Day {}: FAIL ({e})", day.day());
+                failed += 1;
+                continue;
+            }
+        };
+        let hash = inputs::hash(&input);
+        for outcome in outcomes {
+            let label = format!("Day {} - Part {}", day.day(), outcome.part);
+            let answer = match outcome.answer {
+                Ok(answer) => answer,
+                Err(e) => {
+                    println!("{label}: FAIL ({e})");
+                    failed += 1;
+                    continue;
+                }
+            };
+            match answers.check(year, day.day(), outcome.part, &hash, &answer) {
+                Verdict::Pass => {
+                    println!("{label}: pass");
+                    passed += 1;
+                }
+                Verdict::Fail { expected } => {
+                    println!("{label}: FAIL (got {answer}, expected {expected})");
+                    failed += 1;
+                }
+                Verdict::Unknown => {
+                    println!("{label}: missing (no known answer for input {hash}, got {answer})");
+                    missing += 1;
+                }
+            }
+        }
+    }
+    settings.save_cache();
+    println!("\n{passed} passed, {failed} failed, {missing} missing");
+    if failed > 0 {
+        std::process::exit(1);
+    }
+}
+
+fn report(
+    selected: Selected,
+    json: Option<PathBuf>,
+    markdown: Option<PathBuf>,
+    settings: &Settings,
+) {
+    let year = selected.year;
+    let threads = settings.exec.thread_count();
+    let mut rows = Vec::new();
+    for day in selected.days {
+        let outcomes = inputs::load(year, day.day())
+            .map_err(Box::<dyn Error>::from)
+            .and_then(|input| solve(year, day, &input, None, &Overrides::default(), settings));
+        let outcomes = match outcomes {
+            Ok(outcomes) => outcomes,
+            Err(e) => {
+                rows.push(report::Row {
+                    year,
+                    day: day.day(),
+                    part: None,
+                    answer: report::Outcome::Error(e.to_string()),
+                    parse_time: Duration::ZERO,
+                    solve_time: Duration::ZERO,
+                    threads,
+                    parse_allocs: None,
+                    solve_allocs: None,
+                });
+                continue;
+            }
+        };
+        rows.extend(outcomes.into_iter().map(|outcome| report::Row {
+            year,
+            day: day.day(),
+            part: Some(outcome.part),
+            answer: match outcome.answer {
+                Ok(answer) => report::Outcome::Answer(answer),
+                Err(e) => report::Outcome::Error(e.to_string()),
+            },
+            parse_time: outcome.parse_time,
+            solve_time: outcome.solve_time,
+            threads,
+            parse_allocs: outcome.parse_allocs,
+            solve_allocs: outcome.solve_allocs,
+        }));
+    }
+    let report = report::Report::new(rows);
+    if json.is_none() && markdown.is_none() {
+        print!("{}", report.to_markdown());
+    }
+    let outputs = [(json, report.to_json()), (markdown, report.to_markdown())];
+    for (path, text) in outputs {
+        let Some(path) = path else { continue };
+        if let Err(e) = std::fs::write(&path, text) {
+            eprintln!("Could not write {}: {e}", path.display());
+            std::process::exit(1);
+        }
+        println!("Wrote {}", path.display());
+    }
+}
+
+fn lint(selected: Selected, input: Option<PathBuf>) {
+    let year = selected.year;
+    let mut failed = false;
+    for day in selected.days {
+        let path = input
+            .clone()
+            .unwrap_or_else(|| inputs::path(year, day.day()));
+        let contents = match inputs::read(&path) {
+            Ok(contents) => contents,
+            Err(e) => {
+                println!("Day {}: missing ({e})", day.day());
+                // Not every day's input has to be there, unless asked for.
+                failed |= input.is_some();
+                continue;
+            }
+        };
+        let lints = day.lint(&contents);
+        for lint in &lints {
+            println!("{lint}");
+        }
+        // Parsing might still find something the lints didn't look for.
+        let parsed = day.parse(&contents);
+        if let Err(e) = &parsed {
+            println!("{e}");
+        }
+        if lints.is_empty() && parsed.is_ok() {
+            println!("Day {}: ok", day.day());
+        } else {
+            failed = true;
+        }
+    }
+    if failed {
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,191 @@
+//! Answers computed earlier, so that re-running every day only solves what might have changed.
+//!
+//! An answer is reused for the same year, day, part and input (by [`crate::inputs::hash`]),
+//! but only by a build from the same sources (fingerprinted by `build.rs`), so changing
+//! anything in the crate means solving everything again.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// Where the runner keeps its cache, relative to the crate root.
+/// It's under `target/`, so `cargo clean` clears it.
+pub const PATH: &str = "target/aoc-cache.toml";
+
+/// The fingerprint of the sources this was built from.
+const BUILD: &str = env!("AOC_SOURCE_HASH");
+
+#[derive(Debug, Default)]
+pub struct Cache {
+    path: PathBuf,
+    entries: BTreeMap<Key, Entry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Key {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub input_hash: String,
+}
+
+/// A part's answer, and how long it took to compute the first time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// Missing if the day has no such part.
+    pub answer: Option<String>,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+/// How entries are written to disk.
+#[derive(Serialize, Deserialize)]
+struct File {
+    #[serde(default, rename = "entry")]
+    entries: Vec<Record>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Record {
+    year: u16,
+    day: u8,
+    part: u8,
+    input_hash: String,
+    /// Missing from caches written before builds were fingerprinted, so they're dropped.
+    #[serde(default)]
+    build: String,
+    answer: Option<String>,
+    parse_ns: u64,
+    solve_ns: u64,
+}
+
+impl Cache {
+    /// An empty cache, which will replace whatever is at `path` when saved.
+    pub fn empty(path: &Path) -> Self {
+        Self {
+            path: path.to_owned(),
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// The cache at `path`, which is empty if there's nothing there yet.
+    /// Entries from builds of other sources are dropped.
+    pub fn load(path: &Path) -> Result<Self, CacheError> {
+        let mut cache = Self::empty(path);
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(cache),
+            Err(e) => return Err(cache.error(e)),
+        };
+        let file: File = toml::from_str(&text).map_err(|e| cache.error(e))?;
+        for record in file.entries.into_iter().filter(|r| r.build == BUILD) {
+            let key = Key {
+                year: record.year,
+                day: record.day,
+                part: record.part,
+                input_hash: record.input_hash,
+            };
+            let entry = Entry {
+                answer: record.answer,
+                parse_time: Duration::from_nanos(record.parse_ns),
+                solve_time: Duration::from_nanos(record.solve_ns),
+            };
+            cache.entries.insert(key, entry);
+        }
+        Ok(cache)
+    }
+
+    pub fn save(&self) -> Result<(), CacheError> {
+        let entries = self
+            .entries
+            .iter()
+            .map(|(key, entry)| Record {
+                year: key.year,
+                day: key.day,
+                part: key.part,
+                input_hash: key.input_hash.clone(),
+                build: BUILD.to_owned(),
+                answer: entry.answer.clone(),
+                parse_ns: entry.parse_time.as_nanos() as u64,
+                solve_ns: entry.solve_time.as_nanos() as u64,
+            })
+            .collect();
+        let text = toml::to_string(&File { entries }).map_err(|e| self.error(e))?;
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| self.error(e))?;
+        }
+        std::fs::write(&self.path, text).map_err(|e| self.error(e))
+    }
+
+    pub fn get(&self, key: &Key) -> Option<&Entry> {
+        self.entries.get(key)
+    }
+
+    pub fn insert(&mut self, key: Key, entry: Entry) {
+        self.entries.insert(key, entry);
+    }
+
+    fn error(&self, reason: impl ToString) -> CacheError {
+        CacheError {
+            path: self.path.clone(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CacheError {
+    pub path: PathBuf,
+    pub reason: String,
+}
+
+impl fmt::Display for CacheError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "answer cache {}: {}", self.path.display(), self.reason)
+    }
+}
+
+impl std::error::Error for CacheError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let path = std::env::temp_dir().join(format!("aoc-cache-{}.toml", std::process::id()));
+        let key = |part| Key {
+            year: 2024,
+            day: 14,
+            part,
+            input_hash: "abc".into(),
+        };
+        let mut cache = Cache::load(&path).unwrap();
+        assert_eq!(cache.get(&key(1)), None);
+        let answer = Entry {
+            answer: Some("12".into()),
+            parse_time: Duration::from_micros(3),
+            solve_time: Duration::from_millis(5),
+        };
+        let no_part = Entry {
+            answer: None,
+            ..answer.clone()
+        };
+        cache.insert(key(1), answer.clone());
+        cache.insert(key(2), no_part.clone());
+        cache.save().unwrap();
+
+        let cache = Cache::load(&path).unwrap();
+        assert_eq!(cache.get(&key(1)), Some(&answer));
+        assert_eq!(cache.get(&key(2)), Some(&no_part));
+
+        // Other builds' answers might be wrong now.
+        let text = std::fs::read_to_string(&path).unwrap();
+        std::fs::write(&path, text.replace(BUILD, "0123456789abcdef")).unwrap();
+        assert_eq!(Cache::load(&path).unwrap().get(&key(1)), None);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
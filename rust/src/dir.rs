@@ -10,17 +10,21 @@ pub enum Dir {
 
 impl std::fmt::Debug for Dir {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let d = match self {
+        write!(f, "{}", self.arrow())
+    }
+}
+
+impl Dir {
+    /// The character pointing this way, e.g. for drawing which way something's facing.
+    pub fn arrow(self) -> char {
+        match self {
             Dir::Up => '^',
             Dir::Down => 'v',
             Dir::Left => '<',
             Dir::Right => '>',
-        };
-        write!(f, "{}", d)
+        }
     }
-}
 
-impl Dir {
     pub fn turn_right(&mut self) {
         *self = match self {
             Dir::Up => Dir::Right,
@@ -59,6 +63,11 @@ impl Dir {
         curr
     }
 
+    /// How far one step in this direction moves, e.g. for [`crate::grid::Grid::ray`].
+    pub fn offset(self) -> Point {
+        self.step_from(Point::default())
+    }
+
     /// All 4 cardinal directions.
     pub fn all() -> [Self; 4] {
         [Self::Up, Self::Down, Self::Left, Self::Right]
@@ -0,0 +1,328 @@
+//! How a solution is being run: how many threads it can use, and when it should give up.
+//!
+//! Without the `parallel` feature there's no rayon, and everything runs sequentially
+//! whatever [`Threads`] says.
+
+use std::fmt;
+use std::iter::Sum;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+#[cfg(feature = "parallel")]
+use rayon::{ThreadPool, ThreadPoolBuildError, ThreadPoolBuilder};
+
+/// How many threads solutions can use.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Threads {
+    /// Rayon's global pool, which has a thread per core.
+    #[default]
+    Auto,
+    /// Run everything on the calling thread, without rayon.
+    /// Slower, but timings and profiles are much easier to reproduce.
+    Sequential,
+    /// A rayon pool with this many threads.
+    Pool(usize),
+}
+
+impl Threads {
+    /// One thread means running sequentially, rather than in a one-thread pool.
+    pub fn count(n: usize) -> Self {
+        match n {
+            0 => Self::Auto,
+            1 => Self::Sequential,
+            n => Self::Pool(n),
+        }
+    }
+}
+
+/// The threads solutions run on. Clones share the same pool.
+#[derive(Debug, Clone, Default)]
+pub struct Exec {
+    threads: Threads,
+    #[cfg(feature = "parallel")]
+    pool: Option<Arc<ThreadPool>>,
+}
+
+impl Exec {
+    #[cfg(feature = "parallel")]
+    pub fn new(threads: Threads) -> Result<Self, ExecError> {
+        let pool = match threads {
+            Threads::Pool(n) => Some(Arc::new(
+                ThreadPoolBuilder::new()
+                    .num_threads(n)
+                    .build()
+                    .map_err(ExecError)?,
+            )),
+            Threads::Auto | Threads::Sequential => None,
+        };
+        Ok(Self { threads, pool })
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    pub fn new(threads: Threads) -> Result<Self, ExecError> {
+        Ok(Self { threads })
+    }
+
+    pub fn threads(&self) -> Threads {
+        self.threads
+    }
+
+    /// How many threads solutions will actually get.
+    pub fn thread_count(&self) -> usize {
+        match self.threads {
+            #[cfg(feature = "parallel")]
+            Threads::Auto => rayon::current_num_threads(),
+            #[cfg(feature = "parallel")]
+            Threads::Pool(n) => n,
+            _ => 1,
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    fn is_parallel(&self) -> bool {
+        self.threads != Threads::Sequential
+    }
+}
+
+/// The thread pool couldn't be started.
+#[derive(Debug)]
+pub struct ExecError(#[cfg(feature = "parallel")] ThreadPoolBuildError);
+
+impl fmt::Display for ExecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #[cfg(feature = "parallel")]
+        return write!(f, "{}", self.0);
+        #[cfg(not(feature = "parallel"))]
+        write!(f, "no threads without the parallel feature")
+    }
+}
+
+impl std::error::Error for ExecError {}
+
+/// What the [`Context`] helpers can iterate over: anything rayon can also split
+/// between threads, when there's rayon.
+#[cfg(feature = "parallel")]
+pub trait Items: IntoIterator + IntoParallelIterator<Item = <Self as IntoIterator>::Item> {}
+
+#[cfg(feature = "parallel")]
+impl<I> Items for I where I: IntoIterator + IntoParallelIterator<Item = <I as IntoIterator>::Item> {}
+
+/// What the [`Context`] helpers can iterate over.
+#[cfg(not(feature = "parallel"))]
+pub trait Items: IntoIterator {}
+
+#[cfg(not(feature = "parallel"))]
+impl<I: IntoIterator> Items for I {}
+
+/// Limits on how long a solution can run for. Unlimited by default.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Budget {
+    pub time: Option<Duration>,
+    /// How many times hot loops can call [`Context::should_stop`].
+    pub iterations: Option<u64>,
+}
+
+/// Why a solution was stopped early.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    Cancelled,
+    OutOfTime(Duration),
+    OutOfIterations(u64),
+    /// The solution found the input has no answer, e.g. a maze with no way out.
+    NoAnswer(&'static str),
+}
+
+/// Passed to every solution. Long-running solutions check it in their hot loops,
+/// and stop early (returning any answer at all) once it says to.
+/// Whoever ran the solution should then check [`Context::stopped`] before trusting the answer.
+///
+/// Solutions should only use threads through its helpers (like [`Context::map_sum`]),
+/// never through rayon directly, so that they respect [`Threads`].
+#[derive(Debug, Default)]
+pub struct Context {
+    state: Arc<State>,
+    budget: Budget,
+    exec: Exec,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    stopped: AtomicBool,
+    reason: OnceLock<Stop>,
+    iterations: AtomicU64,
+    /// When the time budget runs out, if there is one.
+    deadline: Option<Instant>,
+    /// How many times [`Context::should_stop`] has been called, for when to check the clock.
+    checks: AtomicU64,
+}
+
+/// Checking the clock in hot loops is slow, so [`Context::should_stop`] only
+/// checks it once every this many calls.
+const CLOCK_EVERY: u64 = 64;
+
+impl State {
+    fn stop(&self, reason: Stop) {
+        // Only the first reason counts.
+        let _ = self.reason.set(reason);
+        self.stopped.store(true, Ordering::Relaxed);
+    }
+}
+
+impl Context {
+    /// Starts the clock on the given budget.
+    pub fn new(budget: Budget, exec: Exec) -> Self {
+        let state = State {
+            deadline: budget.time.map(|time| Instant::now() + time),
+            ..State::default()
+        };
+        Self {
+            state: Arc::new(state),
+            budget,
+            exec,
+        }
+    }
+
+    pub fn threads(&self) -> Threads {
+        self.exec.threads
+    }
+
+    /// Runs `f` in this context's thread pool, so that any parallel work it does uses that pool.
+    pub fn install<R: Send>(&self, f: impl FnOnce() -> R + Send) -> R {
+        #[cfg(feature = "parallel")]
+        if let Some(pool) = &self.exec.pool {
+            return pool.install(f);
+        }
+        f()
+    }
+
+    /// Maps each item, and sums the results.
+    pub fn map_sum<I, F, T, S>(&self, items: I, f: F) -> S
+    where
+        I: Items,
+        F: Fn(<I as IntoIterator>::Item) -> T + Sync + Send,
+        T: Send,
+        S: Sum<T> + Sum<S> + Send,
+    {
+        #[cfg(feature = "parallel")]
+        if self.exec.is_parallel() {
+            return items.into_par_iter().map(f).sum();
+        }
+        items.into_iter().map(f).sum()
+    }
+
+    /// Counts the items which match the predicate.
+    pub fn count<I, F>(&self, items: I, predicate: F) -> usize
+    where
+        I: Items,
+        F: Fn(&<I as IntoIterator>::Item) -> bool + Sync + Send,
+    {
+        #[cfg(feature = "parallel")]
+        if self.exec.is_parallel() {
+            return items.into_par_iter().filter(predicate).count();
+        }
+        items.into_iter().filter(predicate).count()
+    }
+
+    /// The first item, in iteration order, which matches the predicate.
+    pub fn find_first<I, F>(&self, items: I, predicate: F) -> Option<<I as IntoIterator>::Item>
+    where
+        I: Items,
+        F: Fn(&<I as IntoIterator>::Item) -> bool + Sync + Send,
+    {
+        #[cfg(feature = "parallel")]
+        if self.exec.is_parallel() {
+            return items.into_par_iter().find_first(predicate);
+        }
+        items.into_iter().find(predicate)
+    }
+
+    /// Tells the solution to stop as soon as it next checks.
+    pub fn cancel(&self) {
+        self.state.stop(Stop::Cancelled);
+    }
+
+    /// Gives up on the part because its input has no answer, saying why.
+    /// Like any stop, whatever the part then returns is thrown away.
+    pub fn fail(&self, reason: &'static str) {
+        self.state.stop(Stop::NoAnswer(reason));
+    }
+
+    /// Hot loops call this once per iteration, and stop early if it's true.
+    pub fn should_stop(&self) -> bool {
+        if let Some(max) = self.budget.iterations {
+            if self.state.iterations.fetch_add(1, Ordering::Relaxed) >= max {
+                self.state.stop(Stop::OutOfIterations(max));
+            }
+        }
+        if let (Some(deadline), Some(time)) = (self.state.deadline, self.budget.time) {
+            let checks = self.state.checks.fetch_add(1, Ordering::Relaxed);
+            if checks.is_multiple_of(CLOCK_EVERY) && Instant::now() >= deadline {
+                self.state.stop(Stop::OutOfTime(time));
+            }
+        }
+        self.state.stopped.load(Ordering::Relaxed)
+    }
+
+    /// Why the solution was told to stop, if it was.
+    pub fn stopped(&self) -> Option<Stop> {
+        self.state.reason.get().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_budget() {
+        let unlimited = Context::default();
+        assert!(!(0..1000).any(|_| unlimited.should_stop()));
+        assert_eq!(unlimited.stopped(), None);
+
+        let ctx = Context::new(
+            Budget {
+                iterations: Some(10),
+                ..Default::default()
+            },
+            Exec::default(),
+        );
+        assert_eq!((0..).take_while(|_| !ctx.should_stop()).count(), 10);
+        assert_eq!(ctx.stopped(), Some(Stop::OutOfIterations(10)));
+
+        let ctx = Context::new(
+            Budget {
+                time: Some(Duration::from_millis(10)),
+                ..Default::default()
+            },
+            Exec::default(),
+        );
+        while !ctx.should_stop() {
+            std::thread::yield_now();
+        }
+        ctx.cancel();
+        assert_eq!(
+            ctx.stopped(),
+            Some(Stop::OutOfTime(Duration::from_millis(10)))
+        );
+    }
+
+    #[test]
+    fn test_threads() {
+        let squares = |ctx: &Context| -> u64 { ctx.map_sum(0..1000u64, |n| n * n) };
+        for threads in [Threads::Auto, Threads::Sequential, Threads::Pool(3)] {
+            let ctx = Context::new(Budget::default(), Exec::new(threads).unwrap());
+            assert_eq!(ctx.install(|| squares(&ctx)), 332833500);
+            assert_eq!(ctx.count(&[1, 2, 3, 4][..], |n| *n % 2 == 0), 2);
+            assert_eq!(ctx.find_first(0..u64::MAX, |n| n * n > 50), Some(8));
+        }
+        assert_eq!(Threads::count(1), Threads::Sequential);
+        let expected = if cfg!(feature = "parallel") { 3 } else { 1 };
+        assert_eq!(
+            Exec::new(Threads::Pool(3)).unwrap().thread_count(),
+            expected
+        );
+    }
+}
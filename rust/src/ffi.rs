@@ -0,0 +1,176 @@
+//! A C interface to every day's solution, so that solutions in other languages
+//! (like the Elixir ones) can be checked against these.
+//!
+//! The crate builds as a `cdylib` exporting these functions, declared in `include/aoc.h`.
+//! After changing them, regenerate the header with `just header`.
+
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::exec::Context;
+use crate::solution::{self, Overrides};
+
+/// The answer was written to the output buffer.
+pub const AOC_OK: i32 = 0;
+/// There's no solution for that day and part.
+pub const AOC_NO_SOLUTION: i32 = 1;
+/// The input isn't UTF-8, or didn't parse. The reason was written to the output buffer.
+pub const AOC_BAD_INPUT: i32 = 2;
+/// The solution failed. The reason was written to the output buffer.
+pub const AOC_FAILED: i32 = 3;
+/// The output buffer is too small. `out_len` was set to the size it needs to be.
+pub const AOC_BUFFER_TOO_SMALL: i32 = 4;
+/// One of the pointers was null.
+pub const AOC_NULL_POINTER: i32 = 5;
+
+/// Solves one part of one day of the latest year, with the default parameters,
+/// like [`aoc_solve_year`].
+///
+/// # Safety
+///
+/// As for [`aoc_solve_year`].
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u8,
+    part: u8,
+    input_ptr: *const u8,
+    input_len: usize,
+    out_buf: *mut u8,
+    out_len: *mut usize,
+) -> i32 {
+    let year = solution::latest().year;
+    aoc_solve_year(year, day, part, input_ptr, input_len, out_buf, out_len)
+}
+
+/// Solves one part of one day of the given year, with the default parameters, writing the answer
+/// (or why there isn't one) to `out_buf` as UTF-8 text, without a terminating NUL.
+///
+/// `*out_len` is the size of `out_buf` on entry, and the length of what was written
+/// on exit. If it didn't fit, nothing is written, and `*out_len` is set to the size needed.
+///
+/// Returns one of the `AOC_` status codes.
+///
+/// # Safety
+///
+/// `input_ptr` must point to `input_len` readable bytes, `out_len` to a writable
+/// `usize`, and `out_buf` to `*out_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve_year(
+    year: u16,
+    day: u8,
+    part: u8,
+    input_ptr: *const u8,
+    input_len: usize,
+    out_buf: *mut u8,
+    out_len: *mut usize,
+) -> i32 {
+    if input_ptr.is_null() || out_buf.is_null() || out_len.is_null() {
+        return AOC_NULL_POINTER;
+    }
+    let input = std::slice::from_raw_parts(input_ptr, input_len);
+    // Unwinding into the caller is undefined behaviour, so a panic is just another failure.
+    let (status, text) = panic::catch_unwind(AssertUnwindSafe(|| solve(year, day, part, input)))
+        .unwrap_or_else(|_| (AOC_FAILED, "the solution panicked".to_owned()));
+    if text.len() > *out_len {
+        *out_len = text.len();
+        return AOC_BUFFER_TOO_SMALL;
+    }
+    std::ptr::copy_nonoverlapping(text.as_ptr(), out_buf, text.len());
+    *out_len = text.len();
+    status
+}
+
+fn solve(year: u16, day: u8, part: u8, input: &[u8]) -> (i32, String) {
+    let Some(day) = solution::get(year, day) else {
+        return (AOC_NO_SOLUTION, format!("no day {day} in {year}"));
+    };
+    let Ok(input) = std::str::from_utf8(input) else {
+        return (AOC_BAD_INPUT, "input isn't UTF-8".to_owned());
+    };
+    let parsed = match day.parse(input) {
+        Ok(parsed) => parsed,
+        Err(e) => return (AOC_BAD_INPUT, e.to_string()),
+    };
+    let ctx = Context::default();
+    match parsed.solve(part, &Overrides::default(), &ctx) {
+        Ok(Some(answer)) => (AOC_OK, answer.to_string()),
+        Ok(None) => (
+            AOC_NO_SOLUTION,
+            format!("day {} has no part {part}", day.day()),
+        ),
+        Err(e) => (AOC_FAILED, e.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(day: u8, part: u8, input: &[u8], capacity: usize) -> (i32, String) {
+        let mut out = vec![0; capacity];
+        let mut len = out.len();
+        let status = unsafe {
+            aoc_solve(
+                day,
+                part,
+                input.as_ptr(),
+                input.len(),
+                out.as_mut_ptr(),
+                &mut len,
+            )
+        };
+        if status == AOC_BUFFER_TOO_SMALL {
+            return (status, len.to_string());
+        }
+        (status, String::from_utf8(out[..len].to_vec()).unwrap())
+    }
+
+    #[test]
+    fn test_aoc_solve() {
+        let input = include_bytes!("../fixtures/2024/day1.txt");
+        assert_eq!(call(1, 2, input, 64), (AOC_OK, "31".to_owned()));
+        assert_eq!(call(1, 2, input, 1), (AOC_BUFFER_TOO_SMALL, "2".to_owned()));
+        assert_eq!(call(99, 1, input, 64).0, AOC_NO_SOLUTION);
+        let mut out = [0; 64];
+        let mut len = out.len();
+        let status = unsafe {
+            aoc_solve_year(
+                2015,
+                1,
+                1,
+                input.as_ptr(),
+                input.len(),
+                out.as_mut_ptr(),
+                &mut len,
+            )
+        };
+        assert_eq!(status, AOC_NO_SOLUTION);
+        assert_eq!(&out[..len], b"no day 1 in 2015");
+        assert_eq!(call(14, 2, b"p=0,4 v=3,-3", 64).0, AOC_NO_SOLUTION);
+        assert_eq!(call(1, 1, b"\xff", 64).0, AOC_BAD_INPUT);
+        assert_eq!(
+            call(1, 1, b"1 2", 128),
+            (
+                AOC_BAD_INPUT,
+                "day 1 input, line 1, column 4: expected \"   \", found end of input".to_owned()
+            )
+        );
+    }
+
+    #[test]
+    fn test_header_up_to_date() {
+        let dir = env!("CARGO_MANIFEST_DIR");
+        let config = cbindgen::Config::from_file(format!("{dir}/cbindgen.toml")).unwrap();
+        let mut generated = Vec::new();
+        cbindgen::Builder::new()
+            .with_config(config)
+            .with_src(format!("{dir}/src/ffi.rs"))
+            .generate()
+            .unwrap()
+            .write(&mut generated);
+        let header = std::fs::read_to_string(format!("{dir}/include/aoc.h")).unwrap();
+        assert!(
+            String::from_utf8(generated).unwrap() == header,
+            "include/aoc.h is out of date: run `just header`"
+        );
+    }
+}
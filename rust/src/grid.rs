@@ -1,5 +1,17 @@
+use std::fmt;
+
+use crate::parse::{ParseError, Source};
 use crate::point::Point;
 
+mod components;
+mod render;
+
+pub use components::{Components, Connectivity};
+pub use render::{render, Render};
+
+/// Derives [`GridCell`] for an enum, with a `#[cell('c')]` attribute giving each variant's character.
+pub use aoc_derive::GridCell;
+
 #[derive(Clone)]
 pub struct Grid<T> {
     pub width: usize,
@@ -22,6 +34,42 @@ where
 }
 
 impl<T> Grid<T> {
+    /// Parses the grid of characters `s`, a slice of `src`'s input, turning each
+    /// character into a cell with `tile`. If `tile` doesn't know a character, it says
+    /// what it expected instead. Every row must be as long as the first.
+    pub fn parse<'a, E: fmt::Display>(
+        src: &Source<'a>,
+        s: &'a str,
+        mut tile: impl FnMut(char) -> Result<Tile<T>, E>,
+    ) -> Result<(Self, Markers<'a>), ParseError> {
+        let (width, height) = src.rectangle(s)?;
+        let mut inner = Vec::with_capacity(width * height);
+        let mut markers = Markers {
+            src: *src,
+            within: s,
+            found: Vec::new(),
+        };
+        for (y, line) in s.lines().enumerate() {
+            for (x, (i, ch)) in line.char_indices().enumerate() {
+                let cell = match tile(ch) {
+                    Ok(Tile::Cell(cell)) => cell,
+                    Ok(Tile::Marker(name, cell)) => {
+                        markers.found.push((name, Point::from((x, y))));
+                        cell
+                    }
+                    Err(expected) => return Err(src.error(&line[i..], expected)),
+                };
+                inner.push(cell);
+            }
+        }
+        let grid = Self {
+            width,
+            height,
+            inner,
+        };
+        Ok((grid, markers))
+    }
+
     #[must_use]
     fn index(&self, x: isize, y: isize) -> usize {
         y as usize * self.width + x as usize
@@ -57,6 +105,85 @@ impl<T> Grid<T> {
         &self.inner[i]
     }
 
+    /// Every cell, with where it is, a row at a time.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.inner
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| (Point::from((i % self.width, i / self.width)), cell))
+    }
+
+    /// Each row, from the top, left to right.
+    pub fn rows(&self) -> impl Iterator<Item = Ray<'_, T>> {
+        (0..self.height).map(|y| self.ray(Point::from((0, y)), Point { x: 1, y: 0 }))
+    }
+
+    /// Each column, from the left, top to bottom.
+    pub fn columns(&self) -> impl Iterator<Item = Ray<'_, T>> {
+        (0..self.width).map(|x| self.ray(Point::from((x, 0)), Point { x: 0, y: 1 }))
+    }
+
+    /// Each diagonal running down and right, like `\`, from the bottom left corner
+    /// to the top right.
+    pub fn diagonals(&self) -> impl Iterator<Item = Ray<'_, T>> {
+        let (width, height) = self.extent();
+        let left = (0..height).rev().map(|y| Point::from((0, y)));
+        let top = (1..width).map(|x| Point::from((x, 0)));
+        left.chain(top)
+            .map(|start| self.ray(start, Point { x: 1, y: 1 }))
+    }
+
+    /// Each diagonal running down and left, like `/`, from the top left corner
+    /// to the bottom right.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = Ray<'_, T>> {
+        let (width, height) = self.extent();
+        let top = (0..width).map(|x| Point::from((x, 0)));
+        let right = (1..height).map(move |y| Point::from((width - 1, y)));
+        top.chain(right)
+            .map(|start| self.ray(start, Point { x: -1, y: 1 }))
+    }
+
+    /// The width and height to find diagonals in. A grid with no cells has none,
+    /// even if it has rows (or columns) of nothing.
+    fn extent(&self) -> (usize, usize) {
+        if self.inner.is_empty() {
+            return (0, 0);
+        }
+        (self.width, self.height)
+    }
+
+    /// The cells up, down, left and right of `point`, that are in the grid.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.neighbours(point, &AROUND[..4])
+    }
+
+    /// The cells around `point`, including diagonally, that are in the grid.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.neighbours(point, &AROUND)
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        point: Point,
+        offsets: &'static [Point],
+    ) -> impl Iterator<Item = (Point, &'a T)> {
+        offsets.iter().filter_map(move |&offset| {
+            let neighbour = point + offset;
+            Some((neighbour, self.get(neighbour)?))
+        })
+    }
+
+    /// The cells from `start` onwards, moving by `step` each time (e.g. a [`Dir::offset`](crate::dir::Dir::offset)),
+    /// until the edge of the grid. `step` mustn't be zero.
+    pub fn ray(&self, start: Point, step: Point) -> Ray<'_, T> {
+        debug_assert_ne!(step, Point::default(), "a ray has to go somewhere");
+        Ray {
+            grid: self,
+            at: start,
+            step,
+        }
+    }
+
     #[must_use]
     pub fn map<U, F>(self, f: F) -> Grid<U>
     where
@@ -79,3 +206,266 @@ where
         self.get(point).copied()
     }
 }
+
+impl<T> Grid<T>
+where
+    T: GridCell + Clone,
+{
+    /// Parses a grid written with the cells' own characters, like [`Grid::parse`].
+    /// Each of `markers` is a character which isn't a cell's, the marker's name,
+    /// and the cell that's under it.
+    pub fn parse_cells<'a>(
+        src: &Source<'a>,
+        s: &'a str,
+        markers: &[(char, &'static str, T)],
+    ) -> Result<(Self, Markers<'a>), ParseError> {
+        Self::parse(src, s, |ch| {
+            if let Some((_, name, cell)) = markers.iter().find(|(marker, ..)| *marker == ch) {
+                return Ok(Tile::Marker(name, cell.clone()));
+            }
+            T::try_from(ch).map(Tile::Cell).map_err(|_| {
+                let chars: Vec<_> = T::CHARS
+                    .iter()
+                    .chain(markers.iter().map(|(ch, ..)| ch))
+                    .collect();
+                one_of(&chars)
+            })
+        })
+    }
+}
+
+/// Steps to a point's neighbours: the 4 cardinal directions, then the 4 diagonals.
+const AROUND: [Point; 8] = [
+    Point { x: 0, y: -1 },
+    Point { x: 0, y: 1 },
+    Point { x: -1, y: 0 },
+    Point { x: 1, y: 0 },
+    Point { x: -1, y: -1 },
+    Point { x: 1, y: -1 },
+    Point { x: -1, y: 1 },
+    Point { x: 1, y: 1 },
+];
+
+/// A walk across a grid in a straight line, from [`Grid::ray`] or one of its lines
+/// like [`Grid::rows`]. Yields each cell with where it is.
+pub struct Ray<'a, T> {
+    grid: &'a Grid<T>,
+    at: Point,
+    step: Point,
+}
+
+impl<'a, T> Iterator for Ray<'a, T> {
+    type Item = (Point, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cell = self.grid.get(self.at)?;
+        let at = self.at;
+        self.at += self.step;
+        Some((at, cell))
+    }
+}
+
+/// Writes the grid the way it's parsed, a row per line.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.width == 0 {
+            return Ok(());
+        }
+        for row in self.inner.chunks(self.width) {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// A cell which is written as a single character, so that a grid of them can be
+/// parsed with [`Grid::parse_cells`] and written back out the same way with `Display`.
+/// Derive it with [`macro@GridCell`].
+pub trait GridCell: TryFrom<char, Error = UnknownCell> + fmt::Display {
+    /// Every cell's character, in the order the variants are declared.
+    const CHARS: &'static [char];
+}
+
+/// A character which isn't any cell's.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownCell {
+    pub found: char,
+    pub expected: &'static [char],
+}
+
+impl fmt::Display for UnknownCell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let expected: Vec<_> = self.expected.iter().collect();
+        write!(f, "expected {}, found {:?}", one_of(&expected), self.found)
+    }
+}
+
+impl std::error::Error for UnknownCell {}
+
+/// Describes the characters, like `one of '#', '.' or 'S'`.
+fn one_of(chars: &[&char]) -> String {
+    match chars {
+        [] => "nothing".to_owned(),
+        [ch] => format!("{ch:?}"),
+        [rest @ .., last] => {
+            let rest: Vec<_> = rest.iter().map(|ch| format!("{ch:?}")).collect();
+            format!("one of {} or {last:?}", rest.join(", "))
+        }
+    }
+}
+
+/// What a character of a grid stands for, when parsing it with [`Grid::parse`].
+pub enum Tile<T> {
+    Cell(T),
+    /// A cell with something named on it, like the start, or a robot.
+    Marker(&'static str, T),
+}
+
+/// Where each [`Tile::Marker`] was found in a parsed grid.
+pub struct Markers<'a> {
+    src: Source<'a>,
+    within: &'a str,
+    found: Vec<(&'static str, Point)>,
+}
+
+impl Markers<'_> {
+    /// Where the marker called `name` is. If there's more than one, the first.
+    pub fn get(&self, name: &str) -> Option<Point> {
+        self.found
+            .iter()
+            .find(|(found, _)| *found == name)
+            .map(|&(_, point)| point)
+    }
+
+    /// Where the marker called `name` is, or an error saying it was `expected`.
+    pub fn require(&self, name: &str, expected: impl fmt::Display) -> Result<Point, ParseError> {
+        self.get(name)
+            .ok_or_else(|| self.src.error_after(self.within, expected))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dir::Dir;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, GridCell)]
+    enum Cell {
+        #[cell('#')]
+        Wall,
+        #[cell('.')]
+        Open,
+    }
+
+    fn tile(ch: char) -> Result<Tile<bool>, &'static str> {
+        match ch {
+            '#' => Ok(Tile::Cell(true)),
+            '.' => Ok(Tile::Cell(false)),
+            'S' => Ok(Tile::Marker("start", false)),
+            _ => Err("one of '#', '.' or 'S'"),
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let src = Source::new(16, "#.#\n.S.\n");
+        let (grid, markers) = Grid::parse(&src, src.text(), tile).unwrap();
+        assert_eq!((grid.width, grid.height), (3, 2));
+        assert_eq!(grid.inner, [true, false, true, false, false, false]);
+        assert_eq!(markers.get("start"), Some(Point { x: 1, y: 1 }));
+        assert_eq!(markers.get("end"), None);
+        assert_eq!(
+            markers
+                .require("end", "an end 'E'")
+                .unwrap_err()
+                .to_string(),
+            "day 16 input, line 3, column 1: expected an end 'E', found end of input"
+        );
+
+        let src = Source::new(16, "#.#\n.x.");
+        let err = Grid::parse(&src, src.text(), tile).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "day 16 input, line 2, column 2: expected one of '#', '.' or 'S', found \"x\""
+        );
+        let src = Source::new(16, "#.#\n.S");
+        let err = Grid::parse(&src, src.text(), tile).err().unwrap();
+        assert_eq!(
+            (err.line, err.expected.as_str()),
+            (2, "a row of 3 characters")
+        );
+    }
+
+    #[test]
+    fn test_parse_cells() {
+        assert_eq!(Cell::try_from('#'), Ok(Cell::Wall));
+        assert_eq!(
+            Cell::try_from('x').unwrap_err().to_string(),
+            "expected one of '#' or '.', found 'x'"
+        );
+
+        let text = "#.#\n.S.\n";
+        let src = Source::new(16, text);
+        let markers = [('S', "start", Cell::Open)];
+        let (grid, found) = Grid::parse_cells(&src, text, &markers).unwrap();
+        assert_eq!(found.get("start"), Some(Point { x: 1, y: 1 }));
+        // Markers are written as the cell under them.
+        assert_eq!(grid.to_string(), "#.#\n...\n");
+        let err = Grid::<Cell>::parse_cells(&src, text, &[]).err().unwrap();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.expected, "one of '#' or '.'");
+    }
+
+    #[test]
+    fn test_iterators() {
+        // 1 2 3
+        // 4 5 6
+        let grid = Grid {
+            width: 3,
+            height: 2,
+            inner: vec![1, 2, 3, 4, 5, 6],
+        };
+        let lines = |lines: Vec<Ray<'_, i32>>| -> Vec<Vec<i32>> {
+            lines
+                .into_iter()
+                .map(|line| line.map(|(_, &n)| n).collect())
+                .collect()
+        };
+        assert_eq!(lines(grid.rows().collect()), [[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(lines(grid.columns().collect()), [[1, 4], [2, 5], [3, 6]]);
+        assert_eq!(
+            lines(grid.diagonals().collect()),
+            [vec![4], vec![1, 5], vec![2, 6], vec![3]]
+        );
+        assert_eq!(
+            lines(grid.anti_diagonals().collect()),
+            [vec![1], vec![2, 4], vec![3, 5], vec![6]]
+        );
+
+        let (p, &n) = grid.iter().nth(4).unwrap();
+        assert_eq!((p, n), (Point { x: 1, y: 1 }, 5));
+        let corner = Point { x: 0, y: 0 };
+        let values =
+            |cells: Vec<(Point, &i32)>| cells.into_iter().map(|(_, &n)| n).collect::<Vec<_>>();
+        assert_eq!(values(grid.neighbours4(corner).collect()), [4, 2]);
+        assert_eq!(values(grid.neighbours8(corner).collect()), [4, 2, 5]);
+        assert_eq!(values(grid.neighbours8(p).collect()), [2, 4, 6, 1, 3]);
+
+        let ray = grid.ray(Point { x: 2, y: 1 }, Dir::Left.offset());
+        assert_eq!(values(ray.collect()), [6, 5, 4]);
+    }
+
+    #[test]
+    fn test_empty() {
+        for (width, height) in [(0, 0), (0, 2), (2, 0)] {
+            let grid = Grid::new(width, height, 1);
+            assert_eq!(grid.to_string(), "");
+            assert_eq!(grid.iter().count(), 0);
+            assert_eq!(grid.diagonals().count(), 0);
+            assert_eq!(grid.anti_diagonals().count(), 0);
+        }
+    }
+}
@@ -0,0 +1,106 @@
+//! Splitting a grid into connected components, e.g. the regions of a garden or
+//! the clusters of robots.
+
+use crate::grid::{Grid, AROUND};
+use crate::point::Point;
+
+/// Which cells are next to each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Only up, down, left and right.
+    Four,
+    /// Diagonally, too.
+    Eight,
+}
+
+impl Connectivity {
+    fn offsets(self) -> &'static [Point] {
+        match self {
+            Self::Four => &AROUND[..4],
+            Self::Eight => &AROUND,
+        }
+    }
+}
+
+/// A grid split into components, from [`Grid::components`].
+pub struct Components {
+    /// Which component each cell is in: its index in `cells`.
+    pub labels: Grid<usize>,
+    /// The cells in each component, starting with its top left cell.
+    /// Components are in the order of their first cell, a row at a time.
+    pub cells: Vec<Vec<Point>>,
+}
+
+impl Components {
+    /// Which component the cell at `point` is in, if it's in the grid.
+    pub fn label(&self, point: Point) -> Option<usize> {
+        self.labels.get(point).copied()
+    }
+}
+
+impl<T> Grid<T> {
+    /// Splits the grid into components: the groups of cells you can get between
+    /// by stepping to neighbouring cells which are the `same` as each other.
+    /// Every cell is in exactly one component, so e.g. walls are grouped too.
+    pub fn components(
+        &self,
+        connectivity: Connectivity,
+        same: impl Fn(&T, &T) -> bool,
+    ) -> Components {
+        let mut labels: Grid<Option<usize>> = Grid::new(self.width, self.height, None);
+        let mut cells = Vec::new();
+        let mut to_explore = Vec::new();
+        for (start, _) in self.iter() {
+            if labels.get_unchecked(start).is_some() {
+                continue;
+            }
+            let label = cells.len();
+            let mut component = Vec::new();
+            labels.set(start, Some(label));
+            to_explore.push(start);
+            while let Some(point) = to_explore.pop() {
+                component.push(point);
+                let cell = self.get_unchecked(point);
+                for (neighbour, other) in self.neighbours(point, connectivity.offsets()) {
+                    if labels.get_unchecked(neighbour).is_none() && same(cell, other) {
+                        labels.set(neighbour, Some(label));
+                        to_explore.push(neighbour);
+                    }
+                }
+            }
+            cells.push(component);
+        }
+        Components {
+            labels: labels.map(|label| label.unwrap()),
+            cells,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_components() {
+        // AAB
+        // ABA
+        let grid = Grid {
+            width: 3,
+            height: 2,
+            inner: "AABABA".chars().collect(),
+        };
+        let four = grid.components(Connectivity::Four, |a, b| a == b);
+        assert_eq!(four.labels.inner, [0, 0, 1, 0, 2, 3]);
+        assert_eq!(four.cells.len(), 4);
+        assert_eq!(four.cells[2], [Point::from((1, 1))]);
+        assert_eq!(four.label(Point::from((2, 1))), Some(3));
+        assert_eq!(four.label(Point::from((3, 1))), None);
+
+        let eight = grid.components(Connectivity::Eight, |a, b| a == b);
+        assert_eq!(eight.labels.inner, [0, 0, 1, 0, 1, 0]);
+        let mut a = eight.cells[0].clone();
+        a.sort_by_key(|p| (p.y, p.x));
+        assert_eq!(a, [(0, 0), (1, 0), (0, 1), (2, 1)].map(Point::from));
+    }
+}
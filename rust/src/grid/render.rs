@@ -0,0 +1,181 @@
+//! Drawing a grid for debugging, with paths, highlights and markers drawn over its cells.
+
+use std::fmt;
+
+use crate::dir::Dir;
+use crate::grid::Grid;
+use crate::point::Point;
+use crate::viz::{self, Color};
+
+/// Draws `grid` a row per line, each cell as it's displayed.
+/// Add overlays with [`Render`]'s methods: later ones are drawn over earlier ones.
+pub fn render<T: fmt::Display>(grid: &Grid<T>) -> Render<'_, T> {
+    Render {
+        grid,
+        colors: None,
+        overlays: Vec::new(),
+        ansi: false,
+    }
+}
+
+/// A grid being drawn. Display it to draw it.
+pub struct Render<'a, T> {
+    grid: &'a Grid<T>,
+    colors: Option<CellColors<'a, T>>,
+    overlays: Vec<Overlay>,
+    ansi: bool,
+}
+
+type CellColors<'a, T> = Box<dyn Fn(Point, &T) -> Option<Color> + 'a>;
+
+enum Overlay {
+    /// A point drawn as the given character.
+    Mark(Point, char, Color),
+    /// Points whose background is coloured, but drawn as they were.
+    Highlight(Vec<Point>, Color),
+}
+
+impl<'a, T: fmt::Display> Render<'a, T> {
+    /// Colours each cell by where it is and what's there, unless something's drawn over it.
+    pub fn colors(mut self, color: impl Fn(Point, &T) -> Option<Color> + 'a) -> Self {
+        self.colors = Some(Box::new(color));
+        self
+    }
+
+    /// Draws a path through `points`, in order: an arrow at each point towards the
+    /// next, or `o` where the next isn't a step away (like at the end).
+    pub fn path(mut self, points: impl IntoIterator<Item = Point>, color: Color) -> Self {
+        let points: Vec<_> = points.into_iter().collect();
+        let next = points.iter().skip(1).map(Some).chain([None]);
+        for (&point, next) in points.iter().zip(next) {
+            let dir = next.and_then(|&next| {
+                Dir::all()
+                    .into_iter()
+                    .find(|dir| dir.step_from(point) == next)
+            });
+            self = match dir {
+                Some(dir) => self.arrow(point, dir, color),
+                None => self.mark(point, 'o', color),
+            };
+        }
+        self
+    }
+
+    /// Colours the background of the cells at `points`.
+    pub fn highlight(mut self, points: impl IntoIterator<Item = Point>, color: Color) -> Self {
+        let points = points.into_iter().collect();
+        self.overlays.push(Overlay::Highlight(points, color));
+        self
+    }
+
+    /// Draws an arrow pointing in the direction `dir`, e.g. for which way something's facing.
+    pub fn arrow(self, at: Point, dir: Dir, color: Color) -> Self {
+        self.mark(at, dir.arrow(), color)
+    }
+
+    /// Draws `ch` at `at`, e.g. for something that moves around the grid.
+    pub fn mark(mut self, at: Point, ch: char, color: Color) -> Self {
+        self.overlays.push(Overlay::Mark(at, ch, color));
+        self
+    }
+
+    /// Draws it with ANSI colours (if the `viz` feature is on). Without them,
+    /// only the overlays' characters are drawn, and highlights can't be seen.
+    pub fn ansi(mut self) -> Self {
+        self.ansi = true;
+        self
+    }
+}
+
+/// How one cell is drawn.
+#[derive(Default)]
+struct Drawn {
+    ch: Option<char>,
+    fg: Option<Color>,
+    bg: Option<Color>,
+}
+
+impl<T: fmt::Display> fmt::Display for Render<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let grid = self.grid;
+        let mut drawn: Vec<_> = (0..grid.inner.len()).map(|_| Drawn::default()).collect();
+        let index = |p: Point| {
+            grid.is_in_bounds(p)
+                .then(|| p.y as usize * grid.width + p.x as usize)
+        };
+        for overlay in &self.overlays {
+            match overlay {
+                Overlay::Mark(p, ch, color) => {
+                    if let Some(i) = index(*p) {
+                        drawn[i].ch = Some(*ch);
+                        drawn[i].fg = Some(*color);
+                    }
+                }
+                Overlay::Highlight(points, color) => {
+                    for i in points.iter().filter_map(|&p| index(p)) {
+                        drawn[i].bg = Some(*color);
+                    }
+                }
+            }
+        }
+        for (i, (cell, drawn)) in grid.inner.iter().zip(drawn).enumerate() {
+            let p = Point::from((i % grid.width, i / grid.width));
+            let fg = drawn.fg.or_else(|| self.colors.as_ref()?(p, cell));
+            let text = match drawn.ch {
+                Some(ch) => ch.to_string(),
+                None => cell.to_string(),
+            };
+            match (self.ansi, fg, drawn.bg) {
+                (false, ..) | (true, None, None) => write!(f, "{text}")?,
+                (true, fg, bg) => {
+                    let mut paint = viz::plain(text);
+                    if let Some(fg) = fg {
+                        paint = paint.fg(fg);
+                    }
+                    if let Some(bg) = bg {
+                        paint = paint.on(bg);
+                    }
+                    write!(f, "{paint}")?;
+                }
+            }
+            if (i + 1) % grid.width == 0 {
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let grid = Grid {
+            width: 4,
+            height: 3,
+            inner: "#...#.#.....".chars().collect(),
+        };
+        let path = [(1, 0), (2, 0), (3, 0), (3, 1), (3, 2), (1, 2)].map(Point::from);
+        let drawn = render(&grid)
+            .path(path, Color::Green)
+            .arrow(Point::from((0, 1)), Dir::Up, Color::Red)
+            .mark(Point::from((9, 9)), '!', Color::Red)
+            .to_string();
+        assert_eq!(drawn, "#>>v\n^.#v\n.o.o\n");
+
+        let drawn = render(&grid)
+            .colors(|_, &ch| (ch == '#').then_some(Color::Blue))
+            .highlight([Point::from((1, 1))], Color::Yellow)
+            .ansi()
+            .to_string();
+        if cfg!(feature = "viz") {
+            let wall = "\x1b[38;5;4m#\x1b[39m\x1b[49m";
+            let highlighted = "\x1b[48;5;3m.\x1b[39m\x1b[49m";
+            assert!(drawn.starts_with(&format!("{wall}...\n{wall}{highlighted}{wall}.\n")));
+        } else {
+            assert_eq!(drawn, grid.to_string());
+        }
+    }
+}
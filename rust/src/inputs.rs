@@ -0,0 +1,123 @@
+//! Finding and reading puzzle inputs at runtime.
+//!
+//! Puzzle inputs aren't checked in, so nothing should need them at compile time.
+
+use std::fmt;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable which overrides the directory inputs are read from.
+/// Like the default `input/`, it has a directory for each year.
+pub const DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Reading from this path reads from stdin instead.
+pub const STDIN: &str = "-";
+
+/// Directory containing the given year's `dayN.txt` inputs.
+pub fn dir(year: u16) -> PathBuf {
+    std::env::var_os(DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("input"))
+        .join(year.to_string())
+}
+
+/// Where the given day's input is expected to be.
+pub fn path(year: u16, day: u8) -> PathBuf {
+    dir(year).join(format!("day{day}.txt"))
+}
+
+/// Read the given day's input from the input directory.
+pub fn load(year: u16, day: u8) -> Result<String, InputError> {
+    read(&path(year, day))
+}
+
+/// Read an input from the given file, or from stdin if the path is [`STDIN`].
+pub fn read(path: &Path) -> Result<String, InputError> {
+    let result = if path == Path::new(STDIN) {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input).map(|_| input)
+    } else {
+        std::fs::read_to_string(path)
+    };
+    result.map_err(|source| InputError {
+        path: path.to_owned(),
+        source,
+    })
+}
+
+/// A short, stable fingerprint of an input's contents (64-bit FNV-1a, in hex).
+/// Trailing newlines are ignored, like they are when solving.
+pub fn hash(input: &str) -> String {
+    let hash = input
+        .trim_end_matches('\n')
+        .bytes()
+        .fold(0xcbf29ce484222325u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+        });
+    format!("{hash:016x}")
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub path: PathBuf,
+    pub source: io::Error,
+}
+
+impl InputError {
+    pub fn is_missing(&self) -> bool {
+        self.source.kind() == io::ErrorKind::NotFound
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "could not read {}: {}", self.path.display(), self.source)
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Used by [`real_input`]. Returns None if the input isn't there, after saying so.
+#[cfg(test)]
+pub(crate) fn for_test(year: u16, day: u8, test: &str) -> Option<String> {
+    use std::io::Write;
+    match load(year, day) {
+        Ok(input) => Some(input),
+        Err(e) if e.is_missing() => {
+            // Write straight to stderr, so the test harness doesn't capture it.
+            let _ = writeln!(
+                io::stderr(),
+                "skipping {test}: no real input at {}",
+                e.path.display()
+            );
+            None
+        }
+        Err(e) => panic!("{e}"),
+    }
+}
+
+/// Get the real puzzle input for a year's day, or skip the current test if it's not there.
+/// Tests that only use examples from the puzzle text shouldn't need this.
+#[cfg(test)]
+macro_rules! real_input {
+    ($year:expr, $day:expr) => {
+        match $crate::inputs::for_test($year, $day, module_path!()) {
+            Some(input) => input,
+            None => return,
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_hash() {
+        assert_eq!(super::hash(""), "cbf29ce484222325");
+        assert_eq!(super::hash("a"), "af63dc4c8601ec8c");
+        assert_eq!(super::hash("1 2\n3 4\n"), super::hash("1 2\n3 4"));
+    }
+}
@@ -1,30 +1,35 @@
-#![feature(array_windows)]
-#![feature(iter_array_chunks)]
-pub mod day1;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
-pub mod day2;
-pub mod day3;
-pub mod day4;
-pub mod day5;
-pub mod day6;
-pub mod day7;
-pub mod day8;
-pub mod day9;
-pub mod day16;
-pub mod day17;
-pub mod day18;
-pub mod day19;
-pub mod day20;
-// Put new mod on this line
+// So that derived code can refer to this crate as `aoc`, like it would anywhere else.
+extern crate self as aoc;
+
+#[macro_use]
+pub mod inputs;
+#[macro_use]
+pub mod trace;
+
+// Each year's solutions
+pub mod y2024;
+// Put new year on this line
 
 // Utilities
+pub mod answer;
+pub mod answers;
+pub mod cache;
 pub mod dir;
+pub mod exec;
+pub mod ffi;
 pub mod grid;
+pub mod lint;
+pub mod memory;
+pub mod parse;
 pub mod point;
+pub mod report;
+pub mod scaffold;
+pub mod serve;
+pub mod solution;
+pub mod viz;
 
+// cargo-aoc only supports one year per crate, so only this year's days use its attributes.
+// It also only passes the input, so days whose parts take `Params` or a `Context`
+// give it a `*_default` shim, which solves with the defaults: the real input's
+// parameters, no budget and the default threads.
 aoc_runner_derive::aoc_lib! { year = 2024 }
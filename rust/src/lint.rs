@@ -0,0 +1,237 @@
+//! Checking a puzzle input's shape before solving it, reporting every problem
+//! (not just the first) with where it is.
+//!
+//! Parsing is forgiving in places: some days work out a grid's width from its
+//! first row, or expect exactly one blank line between sections. Linting finds
+//! inputs that would trip those up, e.g. ones saved with the wrong line endings.
+
+use std::fmt;
+
+use crate::parse::Source;
+
+/// Something wrong with an input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lint {
+    pub day: u8,
+    /// Line number, starting at 1.
+    pub line: usize,
+    /// Column number (in characters), starting at 1.
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {} input, line {}, column {}: {}",
+            self.day, self.line, self.column, self.message
+        )
+    }
+}
+
+/// Collects lints for one input. Like [`Source`], every `&str` given to its
+/// methods must be a slice of the input.
+pub struct Linter<'a> {
+    day: u8,
+    src: Source<'a>,
+    lints: Vec<Lint>,
+}
+
+impl<'a> Linter<'a> {
+    pub fn new(day: u8, text: &'a str) -> Self {
+        Self {
+            day,
+            src: Source::new(day, text),
+            lints: Vec::new(),
+        }
+    }
+
+    /// The whole input, including its final newline.
+    pub fn text(&self) -> &'a str {
+        self.src.text()
+    }
+
+    /// Reports a problem at the start of `at`.
+    pub fn report(&mut self, at: &str, message: impl fmt::Display) {
+        let (line, column) = self.src.location(at);
+        self.lints.push(Lint {
+            day: self.day,
+            line,
+            column,
+            message: message.to_string(),
+        });
+    }
+
+    /// Checks for problems any day's input could have: Windows line endings,
+    /// and whitespace at the ends of lines.
+    pub fn whitespace(&mut self) {
+        let mut crlf = false;
+        for line in self.text().split('\n') {
+            let line = match line.strip_suffix('\r') {
+                Some(content) => {
+                    // Every line probably has one, so only say so once.
+                    if !crlf {
+                        self.report(&line[content.len()..], "CRLF line ending");
+                        crlf = true;
+                    }
+                    content
+                }
+                None => line,
+            };
+            let trimmed = line.trim_end();
+            if trimmed.len() < line.len() {
+                self.report(&line[trimmed.len()..], "trailing whitespace");
+            }
+        }
+    }
+
+    /// Splits the input into sections separated by single blank lines,
+    /// reporting extra blank lines, or a different number of sections than `expected`.
+    pub fn sections(&mut self, expected: usize) -> Vec<&'a str> {
+        let text = self.text();
+        let mut sections = Vec::new();
+        let mut start = None;
+        let mut end = 0;
+        // Extra blank lines only matter if there's something after them.
+        let mut extra_blank = None;
+        let mut offset = 0;
+        for line in text.split_inclusive('\n') {
+            let content = line.trim_end_matches(['\n', '\r']);
+            if content.trim().is_empty() {
+                match start.take() {
+                    Some(start) => sections.push(&text[start..end]),
+                    None => extra_blank = extra_blank.or(Some(line)),
+                }
+            } else {
+                if let Some(blank) = extra_blank.take() {
+                    self.report(blank, "extra blank line");
+                }
+                start.get_or_insert(offset);
+                end = offset + content.len();
+            }
+            offset += line.len();
+        }
+        if let Some(start) = start {
+            sections.push(&text[start..end]);
+        }
+        if sections.len() != expected {
+            let at = sections
+                .get(expected)
+                .copied()
+                .unwrap_or(&text[text.len()..]);
+            self.report(
+                at,
+                format_args!(
+                    "{} sections separated by blank lines, where there should be {expected}",
+                    sections.len()
+                ),
+            );
+        }
+        sections
+    }
+
+    /// Checks every row of the grid `s` is as long as the first.
+    pub fn grid(&mut self, s: &'a str) {
+        let mut lines = s.lines();
+        let Some(first) = lines.next().filter(|line| !line.is_empty()) else {
+            self.report(s, "empty grid");
+            return;
+        };
+        let width = first.chars().count();
+        for line in lines {
+            let len = line.chars().count();
+            if len != width {
+                self.report(
+                    line,
+                    format_args!("row of {len} characters, where the first row has {width}"),
+                );
+            }
+        }
+    }
+
+    /// Checks `s` has exactly one `marker`, which the puzzle calls `name`.
+    pub fn marker(&mut self, s: &'a str, marker: char, name: &str) {
+        let mut found = s.match_indices(marker);
+        let Some((first, _)) = found.next() else {
+            let s = s.trim_end();
+            self.report(&s[s.len()..], format_args!("no {name} {marker:?}"));
+            return;
+        };
+        let (line, column) = self.src.location(&s[first..]);
+        for (i, _) in found {
+            self.report(
+                &s[i..],
+                format_args!(
+                    "another {name} {marker:?}, after the one at line {line}, column {column}"
+                ),
+            );
+        }
+    }
+
+    /// Every lint found, in the order they appear in the input.
+    pub fn finish(mut self) -> Vec<Lint> {
+        self.lints.sort_by_key(|lint| (lint.line, lint.column));
+        self.lints
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lints(text: &str, check: impl FnOnce(&mut Linter<'_>)) -> Vec<String> {
+        let mut linter = Linter::new(16, text);
+        check(&mut linter);
+        linter.finish().iter().map(Lint::to_string).collect()
+    }
+
+    #[test]
+    fn test_whitespace() {
+        assert_eq!(
+            lints("#S.\r\n#. \r\n#E#\r\n", |lint| lint.whitespace()),
+            [
+                "day 16 input, line 1, column 4: CRLF line ending",
+                "day 16 input, line 2, column 3: trailing whitespace",
+            ]
+        );
+        assert!(lints("#S.\n#..\n", |lint| lint.whitespace()).is_empty());
+    }
+
+    #[test]
+    fn test_grid() {
+        let check = |lint: &mut Linter| {
+            let grid = lint.text();
+            lint.grid(grid);
+            lint.marker(grid, 'S', "start");
+            lint.marker(grid, 'E', "end");
+        };
+        assert_eq!(
+            lints("#S.\n#.\n.S.#\n", check),
+            [
+                "day 16 input, line 2, column 1: row of 2 characters, where the first row has 3",
+                "day 16 input, line 3, column 1: row of 4 characters, where the first row has 3",
+                "day 16 input, line 3, column 2: another start 'S', after the one at line 1, column 2",
+                "day 16 input, line 3, column 5: no end 'E'",
+            ]
+        );
+        assert!(lints("#S.\n#.E\n", check).is_empty());
+    }
+
+    #[test]
+    fn test_sections() {
+        let mut linter = Linter::new(5, "1|2\n3|4\n\n\n1,2\n");
+        assert_eq!(linter.sections(2), ["1|2\n3|4", "1,2"]);
+        assert_eq!(
+            linter.finish()[0].to_string(),
+            "day 5 input, line 4, column 1: extra blank line"
+        );
+
+        let mut linter = Linter::new(5, "1|2\n\n1,2\n\n3,4\n\n");
+        assert_eq!(linter.sections(2).len(), 3);
+        assert_eq!(
+            linter.finish()[0].to_string(),
+            "day 5 input, line 5, column 1: 3 sections separated by blank lines, where there should be 2"
+        );
+    }
+}
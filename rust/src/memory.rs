@@ -0,0 +1,168 @@
+//! Counting how much memory solutions allocate, without an external profiler.
+//!
+//! Opt-in, because counting slows every allocation down: the runner only installs
+//! [`Counting`] as its global allocator when built with the `count-allocs` feature.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use serde::Serialize;
+
+/// A global allocator which counts allocations, then hands them to the system allocator.
+///
+/// Counts are shared by every thread, so [`Counting::measure`] includes any work
+/// the measured code does on rayon's threads.
+#[derive(Debug, Default)]
+pub struct Counting {
+    allocations: AtomicU64,
+    allocated: AtomicU64,
+    live: AtomicU64,
+    peak: AtomicU64,
+}
+
+/// The memory used while running some code.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct Stats {
+    /// How many times memory was allocated, including growing an allocation.
+    pub allocations: u64,
+    /// Bytes allocated in total, even if they were freed again.
+    pub allocated: u64,
+    /// The most bytes that were allocated at once, on top of what was already allocated.
+    pub peak: u64,
+}
+
+impl Counting {
+    pub const fn new() -> Self {
+        Self {
+            allocations: AtomicU64::new(0),
+            allocated: AtomicU64::new(0),
+            live: AtomicU64::new(0),
+            peak: AtomicU64::new(0),
+        }
+    }
+
+    /// Runs `f`, and counts what it allocated.
+    /// Measurements don't nest: `f` mustn't call this itself.
+    pub fn measure<R>(&self, f: impl FnOnce() -> R) -> (R, Stats) {
+        let allocations = self.allocations.load(Ordering::Relaxed);
+        let allocated = self.allocated.load(Ordering::Relaxed);
+        let live = self.live.load(Ordering::Relaxed);
+        self.peak.store(live, Ordering::Relaxed);
+        let result = f();
+        let stats = Stats {
+            allocations: self.allocations.load(Ordering::Relaxed) - allocations,
+            allocated: self.allocated.load(Ordering::Relaxed) - allocated,
+            peak: self.peak.load(Ordering::Relaxed).saturating_sub(live),
+        };
+        (result, stats)
+    }
+
+    fn grow(&self, bytes: usize) {
+        let bytes = bytes as u64;
+        self.allocations.fetch_add(1, Ordering::Relaxed);
+        self.allocated.fetch_add(bytes, Ordering::Relaxed);
+        let live = self.live.fetch_add(bytes, Ordering::Relaxed) + bytes;
+        self.peak.fetch_max(live, Ordering::Relaxed);
+    }
+
+    fn shrink(&self, bytes: usize) {
+        self.live.fetch_sub(bytes as u64, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            self.grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            self.grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        self.shrink(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            // Counted as freeing the old allocation and making a new one,
+            // so growing a Vec one push at a time shows up as lots of allocations.
+            self.shrink(layout.size());
+            self.grow(new_size);
+        }
+        new
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocs, {} allocated, {} peak",
+            self.allocations,
+            Bytes(self.allocated),
+            Bytes(self.peak)
+        )
+    }
+}
+
+/// A number of bytes, in the largest unit that keeps it above 1.
+struct Bytes(u64);
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+        if self.0 < 1024 {
+            return write!(f, "{} B", self.0);
+        }
+        let mut size = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while size >= 1024.0 && unit < UNITS.len() - 1 {
+            size /= 1024.0;
+            unit += 1;
+        }
+        write!(f, "{size:.1} {}", UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        let counting = Counting::new();
+        let small = Layout::from_size_align(100, 8).unwrap();
+        let big = Layout::from_size_align(3000, 8).unwrap();
+        let ((), stats) = counting.measure(|| unsafe {
+            let a = counting.alloc(small);
+            let b = counting.alloc_zeroed(big);
+            counting.dealloc(b, big);
+            let a = counting.realloc(a, small, 200);
+            counting.dealloc(a, Layout::from_size_align(200, 8).unwrap());
+        });
+        assert_eq!(
+            stats,
+            Stats {
+                allocations: 3,
+                allocated: 3300,
+                peak: 3100,
+            }
+        );
+        assert_eq!(
+            stats.to_string(),
+            "3 allocs, 3.2 KiB allocated, 3.0 KiB peak"
+        );
+    }
+}
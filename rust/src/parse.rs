@@ -0,0 +1,204 @@
+//! Helpers for turning puzzle input into structured data, reporting
+//! where the input went wrong instead of panicking.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// Puzzle input didn't have the shape a generator expected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    /// Line number, starting at 1.
+    pub line: usize,
+    /// Column number (in characters), starting at 1.
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    /// Replace the description of what was found instead.
+    #[must_use]
+    pub fn found(mut self, found: impl fmt::Display) -> Self {
+        self.found = found.to_string();
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {} input, line {}, column {}: expected {}, found {}",
+            self.day, self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A day's puzzle input. Every `&str` given to its methods must be a
+/// slice of the input, so that errors can say where in the input they happened.
+#[derive(Clone, Copy)]
+pub struct Source<'a> {
+    day: u8,
+    text: &'a str,
+}
+
+impl<'a> Source<'a> {
+    pub fn new(day: u8, text: &'a str) -> Self {
+        Self { day, text }
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn lines(&self) -> std::str::Lines<'a> {
+        self.text.lines()
+    }
+
+    /// The line and column (both starting at 1) of the start of `at`.
+    pub fn location(&self, at: &str) -> (usize, usize) {
+        let before = &self.text[..self.offset(at)];
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or_default();
+        (
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+        )
+    }
+
+    fn offset(&self, at: &str) -> usize {
+        (at.as_ptr() as usize)
+            .checked_sub(self.text.as_ptr() as usize)
+            .filter(|offset| *offset <= self.text.len())
+            .unwrap_or_default()
+    }
+
+    /// An error located at the start of `at`.
+    pub fn error(&self, at: &str, expected: impl fmt::Display) -> ParseError {
+        let (line, column) = self.location(at);
+        ParseError {
+            day: self.day,
+            line,
+            column,
+            expected: expected.to_string(),
+            found: describe(&self.text[self.offset(at)..]),
+        }
+    }
+
+    /// An error located just after the end of `s`.
+    pub fn error_after(&self, s: &str, expected: impl fmt::Display) -> ParseError {
+        self.error(&s[s.len()..], expected)
+    }
+
+    pub fn split_once(
+        &self,
+        s: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        s.split_once(delimiter)
+            .ok_or_else(|| self.error_after(s, format_args!("{delimiter:?}")))
+    }
+
+    pub fn strip_prefix(&self, s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        s.strip_prefix(prefix)
+            .ok_or_else(|| self.error(s, format_args!("{prefix:?}")))
+    }
+
+    pub fn number<T: FromStr>(&self, s: &'a str) -> Result<T, ParseError> {
+        s.parse().map_err(|_| {
+            let e = self.error(s, "a number");
+            if s.is_empty() {
+                e
+            } else {
+                e.found(format_args!("{s:?}"))
+            }
+        })
+    }
+
+    /// Take the next line from `lines`, which are the lines of `within`.
+    pub fn next_line(
+        &self,
+        lines: &mut impl Iterator<Item = &'a str>,
+        within: &'a str,
+        expected: impl fmt::Display,
+    ) -> Result<&'a str, ParseError> {
+        lines
+            .next()
+            .ok_or_else(|| self.error_after(within, expected))
+    }
+
+    /// Checks `s` is a non-empty rectangle of characters, returning its width and height.
+    pub fn rectangle(&self, s: &'a str) -> Result<(usize, usize), ParseError> {
+        let mut lines = s.lines();
+        let first = lines
+            .next()
+            .filter(|line| !line.is_empty())
+            .ok_or_else(|| self.error(s, "a grid"))?;
+        let width = first.chars().count();
+        let mut height = 1;
+        for line in lines {
+            let len = line.chars().count();
+            if len != width {
+                return Err(self
+                    .error(line, format_args!("a row of {width} characters"))
+                    .found(format_args!("{len} characters")));
+            }
+            height += 1;
+        }
+        Ok((width, height))
+    }
+}
+
+/// Describe the input at the start of `rest`, for an error message.
+fn describe(rest: &str) -> String {
+    let Some(first) = rest.chars().next() else {
+        return "end of input".to_owned();
+    };
+    if first == '\n' {
+        return "end of line".to_owned();
+    }
+    let is_word = |c: char| c.is_alphanumeric() || c == '-' || c == '+';
+    if is_word(first) {
+        let end = rest.find(|c| !is_word(c)).unwrap_or(rest.len());
+        format!("{:?}", &rest[..end])
+    } else {
+        format!("{first:?}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_location() {
+        let src = Source::new(1, "12 34\n56 7x\n");
+        let line = src.lines().nth(1).unwrap();
+        let (_, r) = src.split_once(line, " ").unwrap();
+        let err = src.number::<u32>(r).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"day 1 input, line 2, column 4: expected a number, found "7x""#
+        );
+    }
+
+    #[test]
+    fn test_missing_delimiter() {
+        let src = Source::new(5, "1|2\r\n\r\n1,2");
+        let err = src.split_once(src.text(), "\n\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 4));
+        assert_eq!(err.found, "end of input");
+    }
+
+    #[test]
+    fn test_ragged_rectangle() {
+        let src = Source::new(6, "#..\n#.\n...");
+        let err = src.rectangle(src.text()).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.found, "2 characters");
+        let src = Source::new(6, "#.\n.#");
+        assert_eq!(src.rectangle(src.text()).ok(), Some((2, 2)));
+    }
+}
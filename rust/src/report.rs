@@ -0,0 +1,151 @@
+//! A record of how every solution performed, for tracking regressions between commits:
+//! JSON for tools to compare, and a Markdown table for people to read.
+
+use std::fmt::{self, Write};
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::memory::Stats;
+
+#[derive(Debug, Serialize)]
+pub struct Report {
+    /// The crate version that produced the report.
+    pub version: &'static str,
+    pub rows: Vec<Row>,
+}
+
+/// How one part of one day went.
+#[derive(Debug, Serialize)]
+pub struct Row {
+    pub year: u16,
+    pub day: u8,
+    /// Missing if the day failed before any part could run, e.g. because its input didn't parse.
+    pub part: Option<u8>,
+    /// The answer, or why there isn't one.
+    #[serde(flatten)]
+    pub answer: Outcome,
+    #[serde(rename = "parse_ns", serialize_with = "nanos")]
+    pub parse_time: Duration,
+    #[serde(rename = "solve_ns", serialize_with = "nanos")]
+    pub solve_time: Duration,
+    /// How many threads the part could use.
+    pub threads: usize,
+    /// Only counted when the runner was built with the `count-allocs` feature.
+    pub parse_allocs: Option<Stats>,
+    pub solve_allocs: Option<Stats>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Answer(String),
+    Error(String),
+}
+
+fn nanos<S: serde::Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+impl Report {
+    pub fn new(rows: Vec<Row>) -> Self {
+        Self {
+            version: env!("CARGO_PKG_VERSION"),
+            rows,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("reports are always valid JSON")
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::from(
+            "| Year | Day | Part | Answer | Parse | Solve | Threads | Parse allocs | Solve allocs |\n\
+             |-----:|----:|-----:|--------|------:|------:|--------:|-------------:|-------------:|\n",
+        );
+        for row in &self.rows {
+            let answer = match &row.answer {
+                Outcome::Answer(answer) => format!("`{answer}`"),
+                Outcome::Error(e) => format!("**FAILED**: {e}"),
+            };
+            writeln!(
+                out,
+                "| {} | {} | {} | {} | {:.2?} | {:.2?} | {} | {} | {} |",
+                row.year,
+                row.day,
+                row.part.map_or(String::from("-"), |part| part.to_string()),
+                answer.replace('|', "\\|"),
+                row.parse_time,
+                row.solve_time,
+                row.threads,
+                Allocs(row.parse_allocs),
+                Allocs(row.solve_allocs),
+            )
+            .unwrap();
+        }
+        out
+    }
+}
+
+/// A Markdown cell for allocation stats, which might not have been counted.
+struct Allocs(Option<Stats>);
+
+impl fmt::Display for Allocs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(stats) => write!(f, "{stats}"),
+            None => write!(f, "-"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report() {
+        let report = Report::new(vec![
+            Row {
+                year: 2024,
+                day: 1,
+                part: Some(1),
+                answer: Outcome::Answer("11".into()),
+                parse_time: Duration::from_micros(15),
+                solve_time: Duration::from_millis(2),
+                threads: 4,
+                parse_allocs: None,
+                solve_allocs: Some(Stats {
+                    allocations: 3,
+                    allocated: 2048,
+                    peak: 1024,
+                }),
+            },
+            Row {
+                year: 2024,
+                day: 2,
+                part: None,
+                answer: Outcome::Error("bad | input".into()),
+                parse_time: Duration::ZERO,
+                solve_time: Duration::ZERO,
+                threads: 4,
+                parse_allocs: None,
+                solve_allocs: None,
+            },
+        ]);
+        let markdown = report.to_markdown();
+        assert!(markdown.contains(
+            "| 2024 | 1 | 1 | `11` | 15.00µs | 2.00ms | 4 | - | 3 allocs, 2.0 KiB allocated, 1.0 KiB peak |\n"
+        ));
+        assert!(markdown.contains("| 2024 | 2 | - | **FAILED**: bad \\| input |"));
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        let row = &json["rows"][0];
+        assert_eq!(row["answer"], "11");
+        assert_eq!(row["solve_ns"], 2_000_000);
+        assert_eq!(row["solve_allocs"]["peak"], 1024);
+        assert_eq!(json["rows"][1]["error"], "bad | input");
+        assert!(json["rows"][1]["part"].is_null());
+    }
+}
@@ -0,0 +1,254 @@
+//! Adding a new day: its module, an empty example input, a placeholder answer,
+//! and registering it everywhere it needs to be registered.
+
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The new day's module, with `XXX` standing in for the day number.
+const TEMPLATE: &str = include_str!("../TEMPLATE");
+
+/// A new year's module, with `YYYY` standing in for the year.
+const YEAR_TEMPLATE: &str = "//! Advent of Code YYYY.
+
+use crate::solution::Day;
+
+// Put new mod on this line
+
+/// Every day that's been solved, in order.
+pub static DAYS: &[&dyn Day] = &[
+    // Put new day on this line
+];
+";
+
+/// The only year whose days can use cargo-aoc's attributes: see the `aoc_lib!` call in `lib.rs`.
+const CARGO_AOC_YEAR: u16 = 2024;
+
+const MOD_MARKER: &str = "// Put new mod on this line";
+const DAY_MARKER: &str = "    // Put new day on this line";
+const YEAR_MOD_MARKER: &str = "// Put new year on this line";
+const YEAR_MARKER: &str = "    // Put new year on this line";
+
+/// Adds the given day to the crate at `root`, returning the files it created or changed.
+/// The year's module is created too, if this is its first day.
+/// Every file is read and checked before any is written, so e.g. a missing marker
+/// changes nothing; only an error partway through writing can leave some files changed.
+pub fn scaffold(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let module = root.join(format!("src/y{year}/day{day}.rs"));
+    if module.exists() {
+        return Err(ScaffoldError::Exists(module));
+    }
+    let mut module_text = TEMPLATE.replace("XXX", &day.to_string());
+    if year != CARGO_AOC_YEAR {
+        module_text = without_cargo_aoc(&module_text);
+    }
+
+    let mut changed = Vec::new();
+    let year_module = root.join(format!("src/y{year}.rs"));
+    let year_text = if year_module.exists() {
+        read(&year_module)?
+    } else {
+        let lib = root.join("src/lib.rs");
+        let lib_text = insert_before(&lib, YEAR_MOD_MARKER, &format!("pub mod y{year};\n"))?;
+        let solution = root.join("src/solution.rs");
+        let solution_text = insert_before(
+            &solution,
+            YEAR_MARKER,
+            &format!("    Year {{\n        year: {year},\n        days: y{year}::DAYS,\n    }},\n"),
+        )?;
+        changed.push((lib, lib_text));
+        changed.push((solution, solution_text));
+        YEAR_TEMPLATE.replace("YYYY", &year.to_string())
+    };
+    let year_text = insert_text_before(
+        &year_module,
+        year_text,
+        MOD_MARKER,
+        &format!("pub mod day{day};\n"),
+    )?;
+    let year_text = insert_text_before(
+        &year_module,
+        year_text,
+        DAY_MARKER,
+        &format!("    &day{day}::Day{day},\n"),
+    )?;
+    let answers = root.join(crate::answers::PATH);
+    let mut answers_text = read(&answers)?;
+    answers_text.push_str(&format!(
+        "\n# [[answer]]\n# year = {year}\n# day = {day}\n# part = 1\n# answer = \n"
+    ));
+    changed.push((module, module_text));
+    changed.push((year_module, year_text));
+    changed.push((answers, answers_text));
+
+    let mut written = changed
+        .into_iter()
+        .map(|(path, text)| write(&path, &text))
+        .collect::<Result<Vec<_>, _>>()?;
+    // Don't throw away an example that's already been saved.
+    let fixture = root.join(format!("fixtures/{year}/day{day}.txt"));
+    if !fixture.exists() {
+        written.push(write(&fixture, "")?);
+    }
+    Ok(written)
+}
+
+/// The module's text without cargo-aoc's attributes, for years it can't run.
+fn without_cargo_aoc(text: &str) -> String {
+    let mut lines = text.lines().peekable();
+    let mut out = String::new();
+    while let Some(line) = lines.next() {
+        if line.starts_with("use aoc_runner_derive") {
+            // And the blank line after it.
+            lines.next_if(|line| line.is_empty());
+            continue;
+        }
+        if !line.starts_with("#[aoc") {
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// The file's text, with `line` added just before `marker`.
+fn insert_before(path: &Path, marker: &'static str, line: &str) -> Result<String, ScaffoldError> {
+    insert_text_before(path, read(path)?, marker, line)
+}
+
+/// Like [`insert_before`], for a file that's already been read (or not written yet).
+fn insert_text_before(
+    path: &Path,
+    text: String,
+    marker: &'static str,
+    line: &str,
+) -> Result<String, ScaffoldError> {
+    if text.contains(line) {
+        return Err(ScaffoldError::Registered(path.to_owned()));
+    }
+    let i = text.find(marker).ok_or_else(|| ScaffoldError::NoMarker {
+        path: path.to_owned(),
+        marker,
+    })?;
+    Ok(format!("{}{line}{}", &text[..i], &text[i..]))
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    std::fs::read_to_string(path).map_err(|source| ScaffoldError::Io {
+        path: path.to_owned(),
+        source,
+    })
+}
+
+fn write(path: &Path, text: &str) -> Result<PathBuf, ScaffoldError> {
+    let io = |source| ScaffoldError::Io {
+        path: path.to_owned(),
+        source,
+    };
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(io)?;
+    }
+    std::fs::write(path, text).map_err(io)?;
+    Ok(path.to_owned())
+}
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    /// The day's module already exists, and won't be overwritten.
+    Exists(PathBuf),
+    /// The day is already registered in this file.
+    Registered(PathBuf),
+    NoMarker {
+        path: PathBuf,
+        marker: &'static str,
+    },
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Exists(path) => write!(f, "{} already exists", path.display()),
+            Self::Registered(path) => write!(f, "day is already registered in {}", path.display()),
+            Self::NoMarker { path, marker } => {
+                write!(f, "{} has no {:?} line", path.display(), marker.trim())
+            }
+            Self::Io { path, source } => write!(f, "could not access {}: {source}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scaffold() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let write = |path: &str, text: &str| write(&root.join(path), text).unwrap();
+        write(
+            "src/lib.rs",
+            "pub mod y2024;\n// Put new year on this line\n",
+        );
+        write(
+            "src/solution.rs",
+            "static YEARS = &[\n    Year {\n        year: 2024,\n        days: y2024::DAYS,\n    },\n    // Put new year on this line\n];\n",
+        );
+        write(
+            "src/y2024.rs",
+            "pub mod day1;\n// Put new mod on this line\nstatic DAYS = &[\n    &day1::Day1,\n    // Put new day on this line\n];\n",
+        );
+        write("answers.toml", "# Answers\n");
+        let read = |path: &str| read(&root.join(path)).unwrap();
+
+        let changed = scaffold(&root, 2024, 2).unwrap();
+        assert_eq!(changed.len(), 4);
+        assert!(read("src/y2024/day2.rs").contains("impl Solution for Day2 {"));
+        assert!(read("src/y2024/day2.rs").contains("#[aoc(day2, part1)]"));
+        assert!(!read("src/y2024/day2.rs").contains("XXX"));
+        let year = read("src/y2024.rs");
+        assert!(year.contains("pub mod day1;\npub mod day2;\n// Put new mod on this line\n"));
+        assert!(year.contains("    &day2::Day2,\n    // Put new day"));
+        assert!(read("answers.toml").contains("# year = 2024\n# day = 2\n"));
+        assert_eq!(read("fixtures/2024/day2.txt"), "");
+
+        // Running it again would overwrite the new module.
+        assert!(matches!(
+            scaffold(&root, 2024, 2),
+            Err(ScaffoldError::Exists(_))
+        ));
+
+        // A new year gets its own module, without cargo-aoc.
+        let changed = scaffold(&root, 2025, 1).unwrap();
+        assert_eq!(changed.len(), 6);
+        let day = read("src/y2025/day1.rs");
+        assert!(!day.contains("aoc_runner_derive") && !day.contains("#[aoc"));
+        assert!(day.starts_with("use crate::answer::Answer;\n"));
+        assert!(read("src/y2025.rs").contains("//! Advent of Code 2025."));
+        assert!(read("src/y2025.rs").contains("pub mod day1;\n"));
+        assert!(read("src/y2025.rs").contains("    &day1::Day1,\n"));
+        assert_eq!(
+            read("src/lib.rs"),
+            "pub mod y2024;\npub mod y2025;\n// Put new year on this line\n"
+        );
+        assert!(
+            read("src/solution.rs").contains("        year: 2025,\n        days: y2025::DAYS,\n")
+        );
+
+        // Failing a check writes nothing, not even the new year's registration.
+        let lib = read("src/lib.rs");
+        std::fs::remove_file(root.join("answers.toml")).unwrap();
+        assert!(matches!(
+            scaffold(&root, 2026, 1),
+            Err(ScaffoldError::Io { .. })
+        ));
+        assert_eq!(read("src/lib.rs"), lib);
+        assert!(!root.join("src/y2026.rs").exists());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
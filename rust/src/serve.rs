@@ -0,0 +1,254 @@
+//! Solving requests as they arrive, one JSON object per line, so that an editor
+//! can re-run a day on every save without starting a new process each time.
+//!
+//! Each day's most recently parsed input is kept, so re-solving the same input
+//! (e.g. with different parameters) doesn't parse it again.
+
+use std::io::{self, BufRead, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+use fxhash::FxHashMap as HashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::exec::{Budget, Context, Exec};
+use crate::inputs;
+use crate::solution::{self, Overrides, Parsed};
+
+/// One line of input, e.g. `{"day":12,"part":2,"input":"AAAA\nBBCD"}`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Request {
+    /// Copied into the response, to match them up.
+    #[serde(default)]
+    id: Option<serde_json::Value>,
+    /// The latest year if not given.
+    year: Option<u16>,
+    day: u8,
+    /// Solves every part the day has if not given.
+    part: Option<u8>,
+    /// The day's input from the input directory if not given.
+    input: Option<String>,
+    /// Overrides for the day's parameters, e.g. `{"width":7}`.
+    #[serde(default)]
+    params: toml::Table,
+}
+
+/// One line of output.
+#[derive(Debug, Default, Serialize)]
+struct Response {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    year: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    day: Option<u8>,
+    /// Why the request couldn't be solved at all.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    /// Zero if the input was already parsed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_ns: Option<u128>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    parts: Vec<PartResponse>,
+}
+
+#[derive(Debug, Serialize)]
+struct PartResponse {
+    part: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    answer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    solve_ns: u128,
+}
+
+/// The last input parsed for a year's day.
+struct Warm {
+    input_hash: String,
+    parsed: Box<dyn Parsed>,
+}
+
+pub struct Server {
+    budget: Budget,
+    exec: Exec,
+    warm: HashMap<(u16, u8), Warm>,
+}
+
+impl Server {
+    /// Every part gets the given budget, and they all share the given threads.
+    pub fn new(budget: Budget, exec: Exec) -> Self {
+        Self {
+            budget,
+            exec,
+            warm: HashMap::default(),
+        }
+    }
+
+    /// Answers requests from `input` until it ends.
+    pub fn run(&mut self, input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+        for line in input.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let response = self.handle(&line);
+            serde_json::to_writer(&mut output, &response)?;
+            writeln!(output)?;
+            // The client is waiting for this line before it sends the next request.
+            output.flush()?;
+        }
+        Ok(())
+    }
+
+    fn handle(&mut self, line: &str) -> Response {
+        let request: Request = match serde_json::from_str(line) {
+            Ok(request) => request,
+            Err(e) => {
+                return Response {
+                    error: Some(format!("invalid request: {e}")),
+                    ..Default::default()
+                }
+            }
+        };
+        let year = request.year.unwrap_or(solution::latest().year);
+        let mut response = Response {
+            id: request.id.clone(),
+            year: Some(year),
+            day: Some(request.day),
+            ..Default::default()
+        };
+        match self.solve(year, &request) {
+            Ok((parse_time, parts)) => {
+                response.parse_ns = Some(parse_time.as_nanos());
+                response.parts = parts;
+            }
+            Err(e) => response.error = Some(e),
+        }
+        response
+    }
+
+    fn solve(
+        &mut self,
+        year: u16,
+        request: &Request,
+    ) -> Result<(Duration, Vec<PartResponse>), String> {
+        let day = solution::get(year, request.day)
+            .ok_or_else(|| format!("no day {} in {year}", request.day))?;
+        let input = match &request.input {
+            Some(input) => input.clone(),
+            None => inputs::load(year, request.day).map_err(|e| e.to_string())?,
+        };
+        let input_hash = inputs::hash(&input);
+        let key = (year, request.day);
+        let mut parse_time = Duration::ZERO;
+        if self
+            .warm
+            .get(&key)
+            .is_none_or(|warm| warm.input_hash != input_hash)
+        {
+            let start = Instant::now();
+            let parsed = catch_panic(|| day.parse(&input))?.map_err(|e| e.to_string())?;
+            parse_time = start.elapsed();
+            self.warm.insert(key, Warm { input_hash, parsed });
+        }
+        let parsed = &self.warm[&key].parsed;
+
+        let overrides = Overrides::from(request.params.clone());
+        let mut parts = Vec::new();
+        for part in request.part.map_or(vec![1, 2], |part| vec![part]) {
+            let ctx = Context::new(self.budget, self.exec.clone());
+            let start = Instant::now();
+            let (answer, error) = match catch_panic(|| parsed.solve(part, &overrides, &ctx))? {
+                Ok(Some(answer)) => (Some(answer.to_string()), None),
+                Ok(None) if request.part.is_some() => (None, Some(format!("no part {part}"))),
+                Ok(None) => continue,
+                Err(e) => (None, Some(e.to_string())),
+            };
+            parts.push(PartResponse {
+                part,
+                answer,
+                error,
+                solve_ns: start.elapsed().as_nanos(),
+            });
+        }
+        Ok((parse_time, parts))
+    }
+}
+
+/// Runs `f`, turning a panic into an error, so a solution can't take the server down with it
+/// (e.g. on a half-edited input that breaks one of its assumptions).
+fn catch_panic<R>(f: impl FnOnce() -> R) -> Result<R, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|_| "the solution panicked".to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn serve(server: &mut Server, requests: &str) -> Vec<serde_json::Value> {
+        let mut output = Vec::new();
+        server.run(requests.as_bytes(), &mut output).unwrap();
+        String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_serve() {
+        let mut server = Server::new(Budget::default(), Exec::default());
+        let example = serde_json::to_string(include_str!("../fixtures/2024/day18.txt")).unwrap();
+        let no_path = serde_json::to_string("#####\n#S#E#\n#####").unwrap();
+        let responses = serve(
+            &mut server,
+            &format!(
+                r#"{{"id":1,"day":18,"part":1,"input":{example},"params":{{"width":7,"corrupted":12}}}}
+
+{{"id":"again","day":18,"input":{example},"params":{{"width":7,"corrupted":12}}}}
+{{"day":14,"part":2,"input":"p=0,4 v=3,-3"}}
+{{"day":18,"part":1,"input":"5;4"}}
+not json
+{{"year":2015,"day":1}}
+{{"day":16,"part":1,"input":{no_path}}}
+{{"id":"still here","day":14,"part":2,"input":"p=0,4 v=3,-3"}}
+"#
+            ),
+        );
+        assert_eq!(responses.len(), 8);
+
+        assert_eq!(responses[0]["id"], 1);
+        assert_eq!(responses[0]["year"], 2024);
+        assert_eq!(responses[0]["parts"][0]["answer"], "22");
+        assert_ne!(responses[0]["parse_ns"], 0);
+
+        // Same input, so it's still parsed.
+        assert_eq!(responses[1]["id"], "again");
+        assert_eq!(responses[1]["parse_ns"], 0);
+        assert_eq!(responses[1]["parts"][1]["answer"], "6,1");
+
+        assert_eq!(responses[2]["parts"][0]["error"], "no part 2");
+        assert_eq!(
+            responses[3]["error"],
+            "day 18 input, line 1, column 4: expected \",\", found end of input"
+        );
+        assert!(responses[4]["error"]
+            .as_str()
+            .unwrap()
+            .starts_with("invalid request"));
+        assert_eq!(responses[5]["error"], "no day 1 in 2015");
+        assert_eq!(
+            responses[6]["error"],
+            "day 16 input, line 3, column 6: expected a path from 'S' to 'E', found end of input"
+        );
+        assert_eq!(responses[7]["id"], "still here");
+    }
+
+    #[test]
+    fn test_catch_panic() {
+        assert_eq!(catch_panic(|| 1 + 1), Ok(2));
+        let bug = || -> u8 { panic!("a bug") };
+        assert_eq!(catch_panic(bug), Err("the solution panicked".to_owned()));
+    }
+}
@@ -0,0 +1,382 @@
+//! A common interface to every day's solution, so the library can be driven
+//! by our own tools and not just through `cargo aoc`.
+
+use std::fmt;
+
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+use crate::answer::Answer;
+use crate::exec::{Context, Stop};
+use crate::lint::{Lint, Linter};
+use crate::parse::ParseError;
+use crate::*;
+
+/// One day's puzzle: how to parse its input, and how to solve each part.
+pub trait Solution {
+    const DAY: u8;
+
+    /// The puzzle input, after parsing. Shared between threads while solving.
+    type Input: Sync;
+
+    /// Numbers the puzzle text gives, which differ between the worked examples
+    /// and the real input (e.g. grid sizes). Defaults are for the real input.
+    type Params: Default + DeserializeOwned + Sync;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Checks the input's shape, beyond what every day's input is checked for
+    /// (see [`Linter::whitespace`]). Days whose parser would accept a malformed
+    /// input, or only report its first problem, should check it here.
+    fn lint(_lint: &mut Linter) {}
+
+    /// Checks the parameters make sense for this input, e.g. that they don't ask
+    /// for more of it than there is, saying what's wrong if not.
+    fn check_params(_input: &Self::Input, _params: &Self::Params) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn part1(input: &Self::Input, params: &Self::Params, ctx: &Context) -> Answer;
+
+    /// `None` if this day's second part hasn't been solved.
+    fn part2(input: &Self::Input, params: &Self::Params, ctx: &Context) -> Option<Answer>;
+}
+
+/// Parameters for days that don't have any.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NoParams {}
+
+/// A [`Solution`] with its input type hidden, so that every day can be listed together.
+pub trait Day: Sync {
+    fn day(&self) -> u8;
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Parsed>, ParseError>;
+
+    /// Every problem with the input's shape, without parsing it.
+    fn lint(&self, input: &str) -> Vec<Lint>;
+}
+
+/// One day's parsed input, ready to be solved.
+pub trait Parsed {
+    /// The answer to the given part, or `None` if the day has no such part.
+    /// Any parameters not overridden take their defaults.
+    fn solve(
+        &self,
+        part: u8,
+        overrides: &Overrides,
+        ctx: &Context,
+    ) -> Result<Option<Answer>, SolveError>;
+}
+
+impl<S: Solution + Sync + 'static> Day for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Parsed>, ParseError> {
+        // Like cargo-aoc, ignore the newline at the end of the file.
+        let input = S::parse(input.trim_end_matches('\n'))?;
+        Ok(Box::new(Input::<S>(input)))
+    }
+
+    fn lint(&self, input: &str) -> Vec<Lint> {
+        let mut linter = Linter::new(S::DAY, input);
+        linter.whitespace();
+        S::lint(&mut linter);
+        linter.finish()
+    }
+}
+
+struct Input<S: Solution>(S::Input);
+
+impl<S: Solution> Parsed for Input<S> {
+    fn solve(
+        &self,
+        part: u8,
+        overrides: &Overrides,
+        ctx: &Context,
+    ) -> Result<Option<Answer>, SolveError> {
+        let params: S::Params =
+            toml::Value::Table(overrides.0.clone())
+                .try_into()
+                .map_err(|e: toml::de::Error| ParamsError {
+                    day: S::DAY,
+                    reason: e.message().to_owned(),
+                })?;
+        S::check_params(&self.0, &params).map_err(|reason| ParamsError {
+            day: S::DAY,
+            reason,
+        })?;
+        let answer = ctx.install(|| match part {
+            1 => Some(S::part1(&self.0, &params, ctx)),
+            2 => S::part2(&self.0, &params, ctx),
+            _ => None,
+        });
+        // If the solution was stopped early, its answer is meaningless.
+        match ctx.stopped() {
+            Some(stop) => Err(SolveError::Stopped {
+                day: S::DAY,
+                part,
+                stop,
+            }),
+            None => Ok(answer),
+        }
+    }
+}
+
+/// Changes to some of a day's [`Solution::Params`].
+#[derive(Debug, Default, Clone)]
+pub struct Overrides(toml::Table);
+
+impl Overrides {
+    /// Sets the parameter `key`. The value is read as TOML if possible (so `7` is
+    /// a number), and otherwise as a string.
+    pub fn set(&mut self, key: &str, value: &str) {
+        let value = toml::from_str::<toml::Table>(&format!("value = {value}"))
+            .ok()
+            .and_then(|mut table| table.remove("value"))
+            .unwrap_or_else(|| toml::Value::String(value.to_owned()));
+        self.0.insert(key.to_owned(), value);
+    }
+
+    /// No parameters are overridden, so every day uses its defaults.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Parses overrides written like `width=7`.
+    pub fn parse<'a>(pairs: impl IntoIterator<Item = &'a str>) -> Result<Self, String> {
+        let mut overrides = Self::default();
+        for pair in pairs {
+            let (key, value) = pair
+                .split_once('=')
+                .ok_or_else(|| format!("expected a parameter like key=value, found {pair:?}"))?;
+            overrides.set(key.trim(), value.trim());
+        }
+        Ok(overrides)
+    }
+}
+
+impl From<toml::Table> for Overrides {
+    fn from(table: toml::Table) -> Self {
+        Self(table)
+    }
+}
+
+/// The overrides didn't fit the day's parameters.
+#[derive(Debug)]
+pub struct ParamsError {
+    pub day: u8,
+    pub reason: String,
+}
+
+impl fmt::Display for ParamsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day {} parameters: {}", self.day, self.reason)
+    }
+}
+
+impl std::error::Error for ParamsError {}
+
+#[derive(Debug)]
+pub enum SolveError {
+    Params(ParamsError),
+    /// The [`Context`] stopped the solution before it finished.
+    Stopped {
+        day: u8,
+        part: u8,
+        stop: Stop,
+    },
+}
+
+impl From<ParamsError> for SolveError {
+    fn from(e: ParamsError) -> Self {
+        Self::Params(e)
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Params(e) => write!(f, "{e}"),
+            Self::Stopped { day, part, stop } => {
+                match stop {
+                    Stop::Cancelled => write!(f, "cancelled")?,
+                    Stop::OutOfTime(time) => write!(f, "timed out after {time:?}")?,
+                    Stop::OutOfIterations(n) => write!(f, "gave up after {n} iterations")?,
+                    Stop::NoAnswer(reason) => write!(f, "no answer ({reason})")?,
+                }
+                write!(f, " in day{day} part{part}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+/// One year's solutions.
+pub struct Year {
+    pub year: u16,
+    /// Every day that's been solved, in order.
+    pub days: &'static [&'static dyn Day],
+}
+
+/// Every year that has solutions, in order.
+pub static YEARS: &[Year] = &[
+    Year {
+        year: 2024,
+        days: y2024::DAYS,
+    },
+    // Put new year on this line
+];
+
+/// The given year's solutions, if it has any.
+pub fn year(year: u16) -> Option<&'static Year> {
+    YEARS.iter().find(|y| y.year == year)
+}
+
+/// The most recent year, which tools use when they aren't given one.
+pub fn latest() -> &'static Year {
+    YEARS.last().expect("there's at least one year")
+}
+
+/// The given day's solution, if it's been solved.
+pub fn get(year: u16, day: u8) -> Option<&'static dyn Day> {
+    self::year(year)?
+        .days
+        .iter()
+        .copied()
+        .find(|d| d.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exec::{Budget, Exec, Threads};
+
+    const DAY18_EXAMPLE: &str = include_str!("../fixtures/2024/day18.txt");
+
+    fn every_day() -> impl Iterator<Item = (u16, &'static dyn Day)> {
+        YEARS
+            .iter()
+            .flat_map(|year| year.days.iter().map(|&day| (year.year, day)))
+    }
+
+    fn example(year: u16, day: u8) -> String {
+        format!(
+            "{}/fixtures/{year}/day{day}.txt",
+            env!("CARGO_MANIFEST_DIR")
+        )
+    }
+
+    #[test]
+    fn test_registry_in_order() {
+        assert!(YEARS.windows(2).all(|w| w[0].year < w[1].year));
+        for year in YEARS {
+            let days: Vec<_> = year.days.iter().map(|d| d.day()).collect();
+            let expected: Vec<_> = (1..=days.len() as u8).collect();
+            assert_eq!(days, expected, "{}", year.year);
+        }
+        assert_eq!(latest().year, 2024);
+        assert!(get(2015, 1).is_none());
+    }
+
+    #[test]
+    fn test_solve_through_registry() {
+        let none = Overrides::default();
+        let parsed = get(2024, 1).unwrap().parse("3   4\n4   3\n2   5").unwrap();
+        assert_eq!(
+            parsed.solve(1, &none, &Context::default()).unwrap(),
+            Some(Answer::Int(3))
+        );
+        assert_eq!(parsed.solve(3, &none, &Context::default()).unwrap(), None);
+        let parsed = get(2024, 14).unwrap().parse("p=0,4 v=3,-3").unwrap();
+        assert_eq!(parsed.solve(2, &none, &Context::default()).unwrap(), None);
+    }
+
+    #[test]
+    fn test_overrides() {
+        let parsed = get(2024, 18).unwrap().parse(DAY18_EXAMPLE).unwrap();
+        let overrides = Overrides::parse(["width=7", "corrupted = 12"]).unwrap();
+        let answer = parsed
+            .solve(2, &overrides, &Context::default())
+            .unwrap()
+            .unwrap();
+        assert_eq!(answer.to_string(), "6,1");
+
+        let typo = Overrides::parse(["widht=7"]).unwrap();
+        let err = parsed
+            .solve(1, &typo, &Context::default())
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.starts_with("day 18 parameters: unknown field `widht`"),
+            "{err}"
+        );
+        assert!(Overrides::parse(["width"]).is_err());
+
+        // Values the solution can't work with are errors too, rather than panics.
+        let empty = Overrides::parse(["width=0"]).unwrap();
+        let err = parsed.solve(1, &empty, &Context::default()).unwrap_err();
+        assert!(matches!(err, SolveError::Params(_)), "{err}");
+    }
+
+    #[test]
+    fn test_examples_lint_clean() {
+        for (year, day) in every_day() {
+            let n = day.day();
+            let input = std::fs::read_to_string(example(year, n)).unwrap();
+            assert_eq!(day.lint(&input), [], "{year} day {n}");
+        }
+    }
+
+    #[test]
+    fn test_sequential_matches_parallel() {
+        let sequential = Exec::new(Threads::Sequential).unwrap();
+        let pool = Exec::new(Threads::Pool(2)).unwrap();
+        for (year, day) in every_day() {
+            let n = day.day();
+            let parsed = day
+                .parse(&std::fs::read_to_string(example(year, n)).unwrap())
+                .unwrap();
+            let overrides = match (year, n) {
+                (2024, 14) => Overrides::parse(["width=11", "height=7"]).unwrap(),
+                (2024, 18) => Overrides::parse(["width=7", "corrupted=12"]).unwrap(),
+                _ => Overrides::default(),
+            };
+            for part in [1, 2] {
+                let solve = |exec: &Exec| {
+                    let ctx = Context::new(Budget::default(), exec.clone());
+                    parsed.solve(part, &overrides, &ctx).unwrap()
+                };
+                assert_eq!(
+                    solve(&sequential),
+                    solve(&pool),
+                    "{year} day {n} part {part}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_stopped() {
+        // Part 2 has to try 117440 values of register A, so this budget runs out first.
+        let parsed = get(2024, 17)
+            .unwrap()
+            .parse(include_str!("../fixtures/2024/day17.txt"));
+        let parsed = parsed.unwrap();
+        let ctx = Context::new(
+            Budget {
+                iterations: Some(1000),
+                ..Default::default()
+            },
+            Exec::default(),
+        );
+        let err = parsed.solve(2, &Overrides::default(), &ctx).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "gave up after 1000 iterations in day17 part2"
+        );
+    }
+}
@@ -0,0 +1,172 @@
+//! Debugging output from inside solutions, which is compiled out unless the
+//! `trace` feature is on.
+//!
+//! Traces are written to stderr, so they never get mixed up with answers.
+//! Which ones are written is chosen when running, with `AOC_TRACE`: e.g.
+//! `AOC_TRACE=debug` for every trace at `debug` level or above, or
+//! `AOC_TRACE=info,day15=trace` for every `info` trace and all of day 15's.
+
+use std::fmt;
+use std::sync::OnceLock;
+
+/// Environment variable choosing which traces are written. Nothing is, if it's not set.
+pub const VAR: &str = "AOC_TRACE";
+
+/// How much detail a trace has, from least to most.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    /// Once per part, e.g. the size of the search space.
+    Info,
+    /// Once per step, e.g. the state after each move.
+    Debug,
+    /// Everything, e.g. each instruction of a program.
+    Trace,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Info => "info",
+            Self::Debug => "debug",
+            Self::Trace => "trace",
+        })
+    }
+}
+
+impl std::str::FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "info" => Ok(Self::Info),
+            "debug" => Ok(Self::Debug),
+            "trace" => Ok(Self::Trace),
+            _ => Err(format!("unknown trace level {s:?}")),
+        }
+    }
+}
+
+/// Which traces to write, written like `debug,y2024::day17=trace`.
+///
+/// A trace's target is the module it's in, e.g. `aoc::y2024::day17`. A target
+/// filter applies to every module under one with that path (or the end of it),
+/// so `day17`, `y2024::day17` and `y2024` would all apply there.
+/// The filter for the innermost module wins (or the longest, if they're for the same one),
+/// and a bare level applies everywhere else.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Filter {
+    default: Option<Level>,
+    targets: Vec<(String, Level)>,
+}
+
+impl Filter {
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut filter = Self::default();
+        for directive in spec.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((target, level)) => {
+                    let level = level.trim().parse()?;
+                    filter.targets.push((target.trim().to_owned(), level));
+                }
+                None => filter.default = Some(directive.parse()?),
+            }
+        }
+        Ok(filter)
+    }
+
+    /// Whether a trace at `level` from the module `target` should be written.
+    pub fn enabled(&self, level: Level, target: &str) -> bool {
+        let max = self
+            .targets
+            .iter()
+            .filter_map(|(filter, level)| Some((applies(filter, target)?, *level)))
+            .max_by_key(|&(specificity, _)| specificity)
+            .map(|(_, level)| level)
+            .or(self.default);
+        max.is_some_and(|max| level <= max)
+    }
+}
+
+/// If `filter`'s path appears in the module path `target`, how specific it is there:
+/// how deep the module it names is, then how many modules it names.
+fn applies(filter: &str, target: &str) -> Option<(usize, usize)> {
+    let filter: Vec<_> = filter.split("::").collect();
+    let target: Vec<_> = target.split("::").collect();
+    let start = target
+        .windows(filter.len())
+        .rposition(|window| window == filter)?;
+    Some((start + filter.len(), filter.len()))
+}
+
+/// The filter from [`VAR`], read the first time anything is traced.
+pub fn filter() -> &'static Filter {
+    static FILTER: OnceLock<Filter> = OnceLock::new();
+    FILTER.get_or_init(|| {
+        let spec = std::env::var(VAR).unwrap_or_default();
+        Filter::parse(&spec).unwrap_or_else(|e| {
+            eprintln!("Ignoring {VAR}: {e}");
+            Filter::default()
+        })
+    })
+}
+
+pub fn enabled(level: Level, target: &str) -> bool {
+    filter().enabled(level, target)
+}
+
+pub fn write(level: Level, target: &str, message: fmt::Arguments) {
+    let target = target.strip_prefix("aoc::").unwrap_or(target);
+    eprintln!("[{level} {target}] {message}");
+}
+
+/// Writes a trace at the given [`Level`], e.g. `trace!(Debug, "moved {dir:?}")`,
+/// if the `trace` feature is on and [`VAR`] asks for it.
+#[cfg(feature = "trace")]
+macro_rules! trace {
+    ($level:ident, $($arg:tt)+) => {
+        if $crate::trace::enabled($crate::trace::Level::$level, module_path!()) {
+            $crate::trace::write(
+                $crate::trace::Level::$level,
+                module_path!(),
+                format_args!($($arg)+),
+            );
+        }
+    };
+}
+
+/// Writes a trace at the given [`Level`], e.g. `trace!(Debug, "moved {dir:?}")`,
+/// if the `trace` feature is on and [`VAR`] asks for it.
+#[cfg(not(feature = "trace"))]
+macro_rules! trace {
+    ($level:ident, $($arg:tt)+) => {
+        // Never run, but still checked, so traces can't go stale while they're off.
+        if false {
+            let _ = $crate::trace::Level::$level;
+            let _ = format_args!($($arg)+);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter() {
+        let filter = Filter::parse("info, y2024=debug,day15=trace").unwrap();
+        assert!(filter.enabled(Level::Info, "aoc::y2024::day1"));
+        assert!(filter.enabled(Level::Debug, "aoc::y2024::day1"));
+        assert!(!filter.enabled(Level::Trace, "aoc::y2024::day1"));
+        assert!(filter.enabled(Level::Trace, "aoc::y2024::day15"));
+        assert!(!filter.enabled(Level::Debug, "aoc::grid"));
+        // Only whole module names match.
+        assert!(!filter.enabled(Level::Trace, "aoc::y2024::day150"));
+
+        let nothing = Filter::parse("").unwrap();
+        assert!(!nothing.enabled(Level::Info, "aoc::y2024::day1"));
+        assert_eq!(
+            Filter::parse("day15=loud"),
+            Err("unknown trace level \"loud\"".to_owned())
+        );
+    }
+}
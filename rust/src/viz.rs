@@ -0,0 +1,104 @@
+//! Colouring debug output. Without the `viz` feature, it's printed without colour.
+
+use std::fmt;
+
+/// The 16 standard terminal colours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    LightBlack,
+    LightRed,
+    LightGreen,
+    LightYellow,
+    LightBlue,
+    LightMagenta,
+    LightCyan,
+    LightWhite,
+}
+
+/// Something to print in colour. Formatting options like width apply to the value.
+#[derive(Debug, Clone, Copy)]
+pub struct Paint<T> {
+    value: T,
+    // Ignored when printing without colour.
+    #[cfg_attr(not(feature = "viz"), allow(dead_code))]
+    fg: Option<Color>,
+    #[cfg_attr(not(feature = "viz"), allow(dead_code))]
+    bg: Option<Color>,
+}
+
+/// Prints `value` in the given colour.
+pub fn fg<T>(value: T, color: Color) -> Paint<T> {
+    Paint {
+        value,
+        fg: Some(color),
+        bg: None,
+    }
+}
+
+/// Prints `value` in the terminal's own colours, unless it's given some.
+pub fn plain<T>(value: T) -> Paint<T> {
+    Paint {
+        value,
+        fg: None,
+        bg: None,
+    }
+}
+
+impl<T> Paint<T> {
+    /// Prints it in the given colour instead.
+    pub fn fg(mut self, color: Color) -> Self {
+        self.fg = Some(color);
+        self
+    }
+
+    /// Prints it on the given background colour too.
+    pub fn on(mut self, color: Color) -> Self {
+        self.bg = Some(color);
+        self
+    }
+}
+
+#[cfg(feature = "viz")]
+impl<T: fmt::Display> fmt::Display for Paint<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use termion::color::{self, AnsiValue};
+        if let Some(fg) = self.fg {
+            write!(f, "{}", color::Fg(AnsiValue(fg as u8)))?;
+        }
+        if let Some(bg) = self.bg {
+            write!(f, "{}", color::Bg(AnsiValue(bg as u8)))?;
+        }
+        self.value.fmt(f)?;
+        write!(f, "{}{}", color::Fg(color::Reset), color::Bg(color::Reset))
+    }
+}
+
+#[cfg(not(feature = "viz"))]
+impl<T: fmt::Display> fmt::Display for Paint<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paint() {
+        let painted = format!("{:3}", fg('#', Color::Red).on(Color::LightBlue));
+        if cfg!(feature = "viz") {
+            assert_eq!(painted, "\x1b[38;5;1m\x1b[48;5;12m#  \x1b[39m\x1b[49m");
+        } else {
+            assert_eq!(painted, "#  ");
+        }
+    }
+}
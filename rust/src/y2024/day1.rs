@@ -0,0 +1,72 @@
+use std::collections::HashMap;
+
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use crate::answer::Answer;
+use crate::exec::Context;
+use crate::parse::{ParseError, Source};
+use crate::solution::{NoParams, Solution};
+
+type List = Vec<u64>;
+
+#[aoc_generator(day1)]
+fn parse_lists(input: &str) -> Result<(List, List), ParseError> {
+    let src = Source::new(1, input);
+    let (mut l, mut r): (List, List) = src
+        .lines()
+        .map(|line| {
+            let (a, b) = src.split_once(line, "   ")?;
+            Ok((src.number(a)?, src.number(b)?))
+        })
+        .collect::<Result<Vec<(u64, u64)>, ParseError>>()?
+        .into_iter()
+        .unzip();
+    l.sort_unstable();
+    r.sort_unstable();
+    Ok((l, r))
+}
+
+#[aoc(day1, part1)]
+fn part1((l, r): &(List, List)) -> u64 {
+    l.iter().zip(r).map(abs_diff).sum()
+}
+
+#[aoc(day1, part2)]
+fn part2((l, r): &(List, List)) -> u64 {
+    let r = frequencies(r);
+    l.iter()
+        .map(|item| item * r.get(item).copied().unwrap_or_default())
+        .sum()
+}
+
+fn frequencies(list: &List) -> HashMap<u64, u64> {
+    let mut d = HashMap::with_capacity(list.len());
+    for id in list {
+        *d.entry(*id).or_insert(0) += 1;
+    }
+    d
+}
+
+fn abs_diff((a, b): (&u64, &u64)) -> u64 {
+    (*a as i64 - *b as i64).unsigned_abs()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Input = (List, List);
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lists(input)
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params, _ctx: &Context) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params, _ctx: &Context) -> Option<Answer> {
+        Some(part2(input).into())
+    }
+}
@@ -1,5 +1,10 @@
+use crate::answer::Answer;
+use crate::exec::Context;
 use crate::grid::Grid;
+use crate::lint::Linter;
+use crate::parse::{ParseError, Source};
 use crate::point::Point;
+use crate::solution::{NoParams, Solution};
 use aoc_runner_derive::{aoc, aoc_generator};
 use fxhash::FxHashMap as HashMap;
 use fxhash::FxHashSet as HashSet;
@@ -68,29 +73,33 @@ fn adjacent_to(Point { x, y }: Point) -> [Point; 4] {
 }
 
 type Height = u8;
-struct Input {
+pub struct Input {
     grid: Grid<Height>,
     graph: Graph,
     trailheads: Vec<Point>,
 }
 
 #[aoc_generator(day10)]
-fn parse(input: &str) -> Input {
+fn parse(input: &str) -> Result<Input, ParseError> {
     // First, parse the input as a topographic map
     // (i.e. a 2D grid where each cell has an integer height)
-    let height = input.lines().count();
-    let width = input.lines().next().unwrap().len();
+    let src = Source::new(10, input);
+    let (width, height) = src.rectangle(input)?;
     let grid: Vec<Height> = input
         .lines()
         .flat_map(|line| {
-            line.chars().map(|c| {
+            line.char_indices().map(|(i, c)| {
+                if c == '.' {
+                    // Impassable, used in the examples.
+                    return Ok(111);
+                }
                 let Some(digit) = c.to_digit(10) else {
-                    return 111;
+                    return Err(src.error(&line[i..], "a digit or '.'"));
                 };
-                digit.try_into().unwrap()
+                Ok(digit.try_into().unwrap())
             })
         })
-        .collect();
+        .collect::<Result<_, _>>()?;
     let grid = Grid {
         inner: grid,
         width,
@@ -123,11 +132,11 @@ fn parse(input: &str) -> Input {
         }
     }
 
-    Input {
+    Ok(Input {
         grid,
         graph,
         trailheads,
-    }
+    })
 }
 
 #[aoc(day10, part1)]
@@ -156,6 +165,31 @@ fn q2(input: &Input) -> usize {
         .sum()
 }
 
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Input;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn lint(lint: &mut Linter) {
+        let grid = lint.text();
+        lint.grid(grid);
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params, _ctx: &Context) -> Answer {
+        q1(input).into()
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params, _ctx: &Context) -> Option<Answer> {
+        Some(q2(input).into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -172,7 +206,8 @@ mod tests {
 32019012
 01329801
 10456732",
-        );
+        )
+        .unwrap();
         assert_eq!(input.trailheads.len(), 9);
         let expected = 36;
         assert_eq!(q1(&input), expected);
@@ -189,7 +224,8 @@ mod tests {
 7.....7
 8.....8
 9.....9",
-        );
+        )
+        .unwrap();
         assert_eq!(input.trailheads.len(), 1);
         let expected = 2;
         assert_eq!(q1(&input), expected);
@@ -206,7 +242,8 @@ mod tests {
 765.987
 876....
 987....",
-        );
+        )
+        .unwrap();
         assert_eq!(input.trailheads.len(), 1);
         let expected = 4;
         assert_eq!(q1(&input), expected);
@@ -223,7 +260,8 @@ mod tests {
 ...8..3
 ...9..2
 .....01",
-        );
+        )
+        .unwrap();
         assert_eq!(input.trailheads.len(), 2);
         let expected = 3;
         assert_eq!(q1(&input), expected);
@@ -0,0 +1,152 @@
+use aoc_runner_derive::{aoc, aoc_generator};
+use fxhash::FxHashMap as HashMap;
+use serde::Deserialize;
+
+use crate::answer::Answer;
+use crate::exec::Context;
+use crate::parse::{ParseError, Source};
+use crate::solution::Solution;
+
+type Num = u64;
+type Input = HashMap<Num, usize>;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// How many times to blink in part 1.
+    pub part1_blinks: usize,
+    /// How many times to blink in part 2.
+    pub part2_blinks: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            part1_blinks: 25,
+            part2_blinks: 75,
+        }
+    }
+}
+
+#[aoc_generator(day11)]
+fn parse(input: &str) -> Result<Input, ParseError> {
+    let src = Source::new(11, input);
+    let mut m = Input::default();
+    for num in input.split_whitespace() {
+        let num: u64 = src.number(num)?;
+        *m.entry(num).or_default() += 1;
+    }
+    Ok(m)
+}
+
+#[aoc(day11, part1)]
+fn q1_default(input: &Input) -> usize {
+    q1(input, &Params::default())
+}
+
+#[aoc(day11, part2)]
+fn q2_default(input: &Input) -> usize {
+    q2(input, &Params::default())
+}
+
+fn q1(input: &Input, params: &Params) -> usize {
+    solve(input, params.part1_blinks)
+}
+
+fn q2(input: &Input, params: &Params) -> usize {
+    solve(input, params.part2_blinks)
+}
+
+fn solve(input: &Input, n: usize) -> usize {
+    let mut stones = input.to_owned();
+    for _ in 0..n {
+        blink(&mut stones);
+    }
+    stones.values().sum()
+}
+
+fn add_to(m: &mut HashMap<Num, usize>, k: u64, v: usize) {
+    *m.entry(k).or_default() += v;
+}
+
+fn blink(stones: &mut Input) {
+    let old_stones: Vec<_> = stones.drain().collect();
+    for (number, count) in old_stones {
+        if number == 0 {
+            add_to(stones, 1, count);
+        } else if even_num_of_digits(number) {
+            let (l, r) = split(number);
+            add_to(stones, l, count);
+            add_to(stones, r, count);
+        } else {
+            add_to(stones, number * 2024, count);
+        }
+    }
+}
+
+fn even_num_of_digits(n: Num) -> bool {
+    num_digits(n).is_multiple_of(2)
+}
+
+fn num_digits(n: Num) -> u32 {
+    n.ilog10() + 1
+}
+
+/// Splits a number down the middle of its digits.
+/// E.g.
+/// ```
+/// assert_eq!(aoc::y2024::day11::split(1234), (12, 34));
+/// ```
+pub fn split(n: Num) -> (Num, Num) {
+    let tens = 10u64.pow(num_digits(n) / 2);
+    (n / tens, n % tens)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Input;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, params: &Self::Params, _ctx: &Context) -> Answer {
+        q1(input, params).into()
+    }
+
+    fn part2(input: &Self::Input, params: &Self::Params, _ctx: &Context) -> Option<Answer> {
+        Some(q2(input, params).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_num_digits() {
+        assert_eq!(num_digits(111), 3);
+    }
+
+    #[test]
+    fn test_split() {
+        assert_eq!(split(10299234), (1029, 9234));
+        assert_eq!(split(102234), (102, 234));
+        assert_eq!(split(1034), (10, 34));
+    }
+
+    #[test]
+    fn test_example() {
+        let input = parse("125 17").unwrap();
+        let expected = 55312;
+        assert_eq!(q1(&input, &Params::default()), expected);
+        let six_blinks = Params {
+            part1_blinks: 6,
+            ..Params::default()
+        };
+        assert_eq!(q1(&input, &six_blinks), 22);
+    }
+}
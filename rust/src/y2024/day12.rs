@@ -0,0 +1,247 @@
+use crate::{
+    answer::Answer,
+    exec::Context,
+    grid::{Components, Connectivity, Grid},
+    lint::Linter,
+    parse::{ParseError, Source},
+    point::Point,
+    solution::{NoParams, Solution},
+};
+use aoc_runner_derive::{aoc, aoc_generator};
+use fxhash::FxHashSet as HashSet;
+
+type Input = Grid<char>;
+
+#[aoc_generator(day12)]
+fn parse(input: &str) -> Result<Input, ParseError> {
+    let (width, height) = Source::new(12, input).rectangle(input)?;
+    let inner = input
+        .lines()
+        .flat_map(|line| line.chars().collect::<Vec<_>>())
+        .collect();
+    Ok(Grid {
+        width,
+        height,
+        inner,
+    })
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+enum Dir {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+#[aoc(day12, part2)]
+fn q2_default(garden_map: &Input) -> usize {
+    q2(garden_map, &Context::default())
+}
+
+#[aoc(day12, part1)]
+fn q1_default(garden_map: &Input) -> usize {
+    q1(garden_map, &Context::default())
+}
+
+fn q2(garden_map: &Input, ctx: &Context) -> usize {
+    let regions = find_regions(garden_map);
+    ctx.map_sum(&regions.cells, |region| {
+        let area = region.len();
+        let label = regions.label(region[0]);
+        let in_region = |p: Point| regions.label(p) == label;
+        let edges: HashSet<(Point, Dir)> = region
+            .iter()
+            .flat_map(|cell| {
+                let mut edges = vec![];
+                {
+                    let up = cell.up();
+                    if !in_region(up) {
+                        edges.push((*cell, Dir::Up));
+                    }
+                }
+                {
+                    let down = cell.down();
+                    if !in_region(down) {
+                        edges.push((*cell, Dir::Down));
+                    }
+                }
+                {
+                    let left = cell.left();
+                    if !in_region(left) {
+                        edges.push((*cell, Dir::Left));
+                    }
+                }
+                {
+                    let right = cell.right();
+                    if !in_region(right) {
+                        edges.push((*cell, Dir::Right));
+                    }
+                }
+                edges
+            })
+            .collect();
+        let sides = sides_from(edges);
+        area * sides
+    })
+}
+
+fn sides_from(edges: HashSet<(Point, Dir)>) -> usize {
+    edges
+        .iter()
+        .filter(|(point, dir)| match dir {
+            Dir::Up if !edges.contains(&(point.right(), Dir::Up)) => true,
+            Dir::Down if !edges.contains(&(point.right(), Dir::Down)) => true,
+            Dir::Left if !edges.contains(&(point.down(), Dir::Left)) => true,
+            Dir::Right if !edges.contains(&(point.down(), Dir::Right)) => true,
+            _ => false,
+        })
+        .count()
+}
+
+fn q1(garden_map: &Input, ctx: &Context) -> usize {
+    let regions = find_regions(garden_map);
+    ctx.map_sum(&regions.cells, |region| {
+        let area = region.len();
+        let label = regions.label(region[0]);
+        let perimeter: usize = region
+            .iter()
+            .map(|p| {
+                4 - p
+                    .cardinal()
+                    .iter()
+                    .filter(|&&neighbour| regions.label(neighbour) == label)
+                    .count()
+            })
+            .sum();
+        area * perimeter
+    })
+}
+
+/// Each region is a group of touching plots with the same plant.
+fn find_regions(garden_map: &Input) -> Components {
+    garden_map.components(Connectivity::Four, |a, b| a == b)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = Input;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn lint(lint: &mut Linter) {
+        let grid = lint.text();
+        lint.grid(grid);
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params, ctx: &Context) -> Answer {
+        q1(input, ctx).into()
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params, ctx: &Context) -> Option<Answer> {
+        Some(q2(input, ctx).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_example_small() {
+        let input = parse(
+            "AAAA
+BBCD
+BBCC
+EEEC",
+        )
+        .unwrap();
+        let regions = find_regions(&input);
+        debug_regions(&regions.labels, &input);
+        assert_eq!(q1(&input, &Context::default()), 140);
+        assert_eq!(q2(&input, &Context::default()), 80);
+    }
+
+    #[test]
+    fn test_example_2() {
+        let input = parse(
+            "EEEEE
+EXXXX
+EEEEE
+EXXXX
+EEEEE",
+        )
+        .unwrap();
+        let regions = find_regions(&input);
+        debug_regions(&regions.labels, &input);
+        assert_eq!(q2(&input, &Context::default()), 236);
+    }
+
+    #[test]
+    fn test_example_3() {
+        let input = parse(
+            "AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA",
+        )
+        .unwrap();
+        let regions = find_regions(&input);
+        debug_regions(&regions.labels, &input);
+        assert_eq!(q2(&input, &Context::default()), 368, "wrong answer for Q2");
+    }
+
+    #[test]
+    fn test_example_bigger() {
+        let input = parse(
+            "RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE",
+        )
+        .unwrap();
+        let regions = find_regions(&input);
+        debug_regions(&regions.labels, &input);
+        assert_eq!(q1(&input, &Context::default()), 1930);
+        assert_eq!(q2(&input, &Context::default()), 1206);
+    }
+    fn debug_regions(r: &Grid<usize>, garden_map: &Grid<char>) {
+        use crate::viz::{self, Color::*};
+        const COLORS: [viz::Color; 15] = [
+            Red,
+            Green,
+            Blue,
+            Magenta,
+            LightYellow,
+            Cyan,
+            White,
+            Yellow,
+            LightRed,
+            LightGreen,
+            LightBlue,
+            LightMagenta,
+            LightBlack,
+            LightCyan,
+            LightWhite,
+        ];
+        // Neighbouring regions might get the same colour, but it's unlikely.
+        let drawn = crate::grid::render(garden_map)
+            .colors(|p, _| Some(COLORS[r.get_unchecked(p) % COLORS.len()]))
+            .ansi();
+        print!("{drawn}");
+    }
+}
@@ -1,13 +1,17 @@
 use aoc_runner_derive::{aoc, aoc_generator};
 
+use crate::answer::Answer;
+use crate::exec::Context;
+use crate::parse::{ParseError, Source};
 use crate::point::Point;
+use crate::solution::{NoParams, Solution};
 
 type Input = Vec<Machine>;
 
 const COST_A: usize = 3;
 const COST_B: usize = 1;
 
-struct Machine {
+pub struct Machine {
     /// How far the claw moves when pressing A.
     a: Point,
     /// How far the claw moves when pressing B.
@@ -42,26 +46,40 @@ impl Machine {
     }
 }
 
-fn parse_two_nums(s: &str) -> (&str, &str) {
-    s.split_once(": ").unwrap().1.split_once(", ").unwrap()
+/// Parses a line like `Button A: X+94, Y+34`, where `sign` is the `+` after X and Y.
+fn parse_two_nums<'a>(
+    src: &Source<'a>,
+    line: &'a str,
+    label: &str,
+    sign: char,
+) -> Result<Point, ParseError> {
+    let nums = src.strip_prefix(line, label)?;
+    let (x, y) = src.split_once(nums, ", ")?;
+    let x = src.strip_prefix(x, &format!("X{sign}"))?;
+    let y = src.strip_prefix(y, &format!("Y{sign}"))?;
+    Ok(Point {
+        x: src.number(x)?,
+        y: src.number(y)?,
+    })
 }
 
 #[aoc_generator(day13)]
-fn parse(input: &str) -> Input {
+fn parse(input: &str) -> Result<Input, ParseError> {
+    let src = Source::new(13, input);
     input
         .split("\n\n")
         .map(|paragraph| {
-            let mut lines = paragraph.trim().lines();
-            let (ax, ay) = parse_two_nums(lines.next().unwrap());
-            let (bx, by) = parse_two_nums(lines.next().unwrap());
-            let (px, py) = parse_two_nums(lines.next().unwrap());
-            let [ax, ay, bx, by, px, py] =
-                [ax, ay, bx, by, px, py].map(|s| s[2..].parse::<isize>().unwrap());
-            Machine {
-                a: Point { x: ax, y: ay },
-                b: Point { x: bx, y: by },
-                prize: Point { x: px, y: py },
-            }
+            let paragraph = paragraph.trim();
+            let mut lines = paragraph.lines();
+            let mut next_line = |label: &str, sign| {
+                let line = src.next_line(&mut lines, paragraph, format_args!("{label:?}"))?;
+                parse_two_nums(&src, line, label, sign)
+            };
+            Ok(Machine {
+                a: next_line("Button A: ", '+')?,
+                b: next_line("Button B: ", '+')?,
+                prize: next_line("Prize: ", '=')?,
+            })
         })
         .collect()
 }
@@ -82,6 +100,26 @@ fn q2(input: &Input) -> usize {
         .sum()
 }
 
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = Input;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params, _ctx: &Context) -> Answer {
+        q1(input).into()
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params, _ctx: &Context) -> Option<Answer> {
+        Some(q2(input).into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -105,7 +143,8 @@ Button A: X+69, Y+23
 Button B: X+27, Y+71
 Prize: X=18641, Y=10279
 ",
-        );
+        )
+        .unwrap();
 
         let expected = 480;
         assert_eq!(q1(&input), expected);
@@ -113,7 +152,7 @@ Prize: X=18641, Y=10279
 
     #[test]
     fn test_real() {
-        let input = parse(include_str!("../input/2024/day13.txt"));
+        let input = parse(&real_input!(2024, 13)).unwrap();
         assert_eq!(q1(&input), 35574);
         assert_eq!(q2(&input), 80882098756071);
     }
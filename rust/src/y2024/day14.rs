@@ -0,0 +1,225 @@
+use std::num::NonZeroUsize;
+
+use aoc_runner_derive::{aoc, aoc_generator};
+use serde::Deserialize;
+
+use crate::answer::Answer;
+use crate::exec::Context;
+use crate::grid::{self, Grid};
+use crate::parse::{ParseError, Source};
+use crate::point::Point;
+use crate::solution::Solution;
+use crate::viz::Color;
+
+type Input = Vec<Robot>;
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// Size of the space the robots move around in.
+    pub width: NonZeroUsize,
+    pub height: NonZeroUsize,
+    /// How long to let the robots move for.
+    pub seconds: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            width: NonZeroUsize::new(101).unwrap(),
+            height: NonZeroUsize::new(103).unwrap(),
+            seconds: 100,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Robot {
+    position: Point,
+    velocity: Point,
+}
+
+impl Robot {
+    /// Parses a line like `p=0,4 v=3,-3`.
+    fn parse<'a>(src: &Source<'a>, s: &'a str) -> Result<Self, ParseError> {
+        let (p, v) = src.split_once(s, " ")?;
+        let (px, py) = src.split_once(src.strip_prefix(p, "p=")?, ",")?;
+        let (vx, vy) = src.split_once(src.strip_prefix(v, "v=")?, ",")?;
+        Ok(Self {
+            position: Point {
+                x: src.number(px)?,
+                y: src.number(py)?,
+            },
+            velocity: Point {
+                x: src.number(vx)?,
+                y: src.number(vy)?,
+            },
+        })
+    }
+
+    fn step(&mut self, width: usize, height: usize) {
+        self.position += self.velocity;
+        self.position %= Point {
+            x: width as isize,
+            y: height as isize,
+        };
+        if self.position.x < 0 {
+            self.position.x += width as isize;
+        }
+        if self.position.y < 0 {
+            self.position.y += height as isize;
+        }
+    }
+
+    fn quadrant(self, width: usize, height: usize) -> Option<Quadrant> {
+        let mw = (width as isize) / 2;
+        let mh = (height as isize) / 2;
+        let Point { x, y } = self.position;
+        if x > mw && y < mh {
+            Some(Quadrant::Tr)
+        } else if x > mw && y > mh {
+            Some(Quadrant::Br)
+        } else if x < mw && y < mh {
+            Some(Quadrant::Tl)
+        } else if x < mw && y > mh {
+            Some(Quadrant::Bl)
+        } else {
+            None
+        }
+    }
+}
+
+#[allow(dead_code)]
+fn debug(robots: &[Robot], width: usize, height: usize) {
+    let mut counts = Grid::new(width, height, 0);
+    for r in robots {
+        let count = counts.get_unchecked(r.position) + 1;
+        counts.set(r.position, count);
+    }
+    // Any more than 9 robots on one tile would make the row wider.
+    let tiles = counts.map(|count| match count {
+        0 => ' ',
+        1..=9 => char::from_digit(count, 10).unwrap(),
+        _ => '+',
+    });
+    let drawn = grid::render(&tiles)
+        .colors(|_, &tile| (tile != ' ').then_some(Color::Green))
+        .ansi();
+    print!("{drawn}");
+}
+
+enum Quadrant {
+    Tl,
+    Tr,
+    Bl,
+    Br,
+}
+
+#[aoc_generator(day14)]
+fn parse(input: &str) -> Result<Input, ParseError> {
+    let src = Source::new(14, input);
+    input.lines().map(|line| Robot::parse(&src, line)).collect()
+}
+
+#[aoc(day14, part1)]
+fn q1_default(robots: &Input) -> usize {
+    q1(robots, &Params::default())
+}
+
+fn q1(robots: &Input, params: &Params) -> usize {
+    let Params {
+        width,
+        height,
+        seconds,
+    } = *params;
+    let (width, height) = (width.get(), height.get());
+    let mut robots = robots.to_owned();
+    for _ in 0..seconds {
+        robots
+            .iter_mut()
+            .for_each(|r| Robot::step(r, width, height));
+    }
+    robots
+        .into_iter()
+        .filter_map(|r| Robot::quadrant(r, width, height))
+        .fold(
+            [0, 0, 0, 0],
+            |[mut tl, mut tr, mut bl, mut br], quadrant| {
+                match quadrant {
+                    Quadrant::Tl => tl += 1,
+                    Quadrant::Tr => tr += 1,
+                    Quadrant::Bl => bl += 1,
+                    Quadrant::Br => br += 1,
+                }
+
+                [tl, tr, bl, br]
+            },
+        )
+        .into_iter()
+        .product()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = Input;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, params: &Self::Params, _ctx: &Context) -> Answer {
+        q1(input, params).into()
+    }
+
+    fn part2(_input: &Self::Input, _params: &Self::Params, _ctx: &Context) -> Option<Answer> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step() {
+        let mut robot = parse("p=2,4 v=2,-3").unwrap().pop().unwrap();
+        let width = 11;
+        let height = 7;
+        for _ in 0..6 {
+            println!("{}", robot.position);
+            debug(&[robot], width, height);
+            println!();
+            robot.step(width, height);
+        }
+    }
+
+    #[test]
+    fn test_example() {
+        let input = parse(
+            "p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3",
+        )
+        .unwrap();
+        let expected = 12;
+        let params = Params {
+            width: NonZeroUsize::new(11).unwrap(),
+            height: NonZeroUsize::new(7).unwrap(),
+            ..Params::default()
+        };
+        debug(&input, params.width.get(), params.height.get());
+        assert_eq!(q1(&input, &params), expected);
+    }
+}
@@ -1,12 +1,18 @@
+use crate::answer::Answer;
 use crate::dir::Dir;
+use crate::exec::Context;
+use crate::grid::{self, GridCell, Render, Tile};
+use crate::lint::Linter;
+use crate::parse::{ParseError, Source};
+use crate::solution::{NoParams, Solution};
+use crate::viz::Color;
 use aoc_runner_derive::{aoc, aoc_generator};
 use itertools::Itertools;
-use termion::color;
 
 use crate::point::Point;
 
 #[derive(Clone)]
-struct Input {
+pub struct Input {
     grid: Grid,
     player: Point,
     instructions: Vec<Dir>,
@@ -14,31 +20,19 @@ struct Input {
 
 type Grid = crate::grid::Grid<Cell>;
 
-#[allow(dead_code)]
-fn print(grid: &Grid, player: Point) {
-    for y in 0..grid.height {
-        for x in 0..grid.width {
-            let p = Point::from((x, y));
-            if player == p {
-                print!("{}@{}", color::Fg(color::Red), color::Fg(color::Reset));
-                continue;
-            }
-            let ch = grid.get_unchecked(p);
-            let sigil_fg: Box<dyn color::Color> = match ch {
-                Cell::Wall => Box::new(color::Blue),
-                Cell::Block => Box::new(color::White),
-                Cell::BlockLeft | Cell::BlockRight => Box::new(color::Cyan),
-                Cell::Empty => Box::new(color::White),
-            };
-            print!(
-                "{}{ch}{}",
-                color::Fg(sigil_fg.as_ref()),
-                color::Fg(color::Reset)
-            );
-        }
-        println!();
-    }
-    println!();
+/// The grid with the player on it, for debugging.
+fn show(grid: &Grid, player: Point) -> Render<'_, Cell> {
+    grid::render(grid)
+        .colors(|_, cell| {
+            Some(match cell {
+                Cell::Wall => Color::Blue,
+                Cell::Block => Color::White,
+                Cell::BlockLeft | Cell::BlockRight => Color::Cyan,
+                Cell::Empty => Color::White,
+            })
+        })
+        .mark(player, '@', Color::Red)
+        .ansi()
 }
 
 fn expand(grid: Grid) -> Grid {
@@ -73,49 +67,36 @@ fn expand(grid: Grid) -> Grid {
 }
 
 #[aoc_generator(day15)]
-fn parse(input: &str) -> Input {
-    let (input, instructions) = input.split_once("\n\n").unwrap();
-    let width = input.lines().next().unwrap().len();
-    let height = input.lines().count();
-    let mut inner = Vec::with_capacity(width * height);
-    let mut player = None;
-    for (y, line) in input.lines().enumerate() {
-        for (x, ch) in line.trim().chars().enumerate() {
-            let cell = match ch {
-                '#' => Cell::Wall,
-                '.' => Cell::Empty,
-                'O' => Cell::Block,
-                '@' => {
-                    // foo
-                    player = Some(Point::from((x, y)));
-                    Cell::Empty
-                }
-                _ => unreachable!(),
-            };
-            inner.push(cell);
-        }
-    }
+fn parse(input: &str) -> Result<Input, ParseError> {
+    let src = Source::new(15, input);
+    let (map, instructions) = src.split_once(input, "\n\n")?;
+    // Not `Grid::parse_cells`: the wide boxes only appear once the map's been expanded.
+    let (grid, markers) = Grid::parse(&src, map, |ch| match ch {
+        '#' => Ok(Tile::Cell(Cell::Wall)),
+        '.' => Ok(Tile::Cell(Cell::Empty)),
+        'O' => Ok(Tile::Cell(Cell::Block)),
+        '@' => Ok(Tile::Marker("robot", Cell::Empty)),
+        _ => Err("one of '#', '.', 'O' or '@'"),
+    })?;
+    let player = markers.require("robot", "a robot '@'")?;
     let instructions = instructions
         .lines()
         .flat_map(|line| {
-            line.trim().chars().map(|ch| match ch {
-                'v' => Dir::Down,
-                '^' => Dir::Up,
-                '<' => Dir::Left,
-                '>' => Dir::Right,
-                _ => unreachable!(),
+            let line = line.trim();
+            line.char_indices().map(move |(i, ch)| match ch {
+                'v' => Ok(Dir::Down),
+                '^' => Ok(Dir::Up),
+                '<' => Ok(Dir::Left),
+                '>' => Ok(Dir::Right),
+                _ => Err(src.error(&line[i..], "one of '^', 'v', '<' or '>'")),
             })
         })
-        .collect();
-    Input {
-        grid: Grid {
-            width,
-            height,
-            inner,
-        },
-        player: player.unwrap(),
+        .collect::<Result<_, _>>()?;
+    Ok(Input {
+        grid,
+        player,
         instructions,
-    }
+    })
 }
 
 fn shift_blocks(dir: Dir, player: Point, grid: &mut Grid) {
@@ -142,7 +123,7 @@ fn shift_blocks(dir: Dir, player: Point, grid: &mut Grid) {
         }
     };
     // `end` is now the first empty space after this stack of blocks.
-    // println!("Shifting {start}, {end}");
+    trace!(Trace, "Shifting {start}, {end}");
     grid.set(end, Cell::Block);
     grid.set(start, Cell::Empty);
 }
@@ -183,8 +164,7 @@ fn try_move(dir: Dir, player: &mut Point, grid: &mut Grid) {
 
 #[aoc(day15, part2)]
 fn q2(input: &Input) -> usize {
-    // println!("Input grid:");
-    // print(&input.grid, input.player);
+    trace!(Debug, "Input grid:\n{}", show(&input.grid, input.player));
     let mut grid = expand(input.grid.clone());
     let mut player = Point {
         x: input.player.x * 2,
@@ -266,7 +246,7 @@ fn q2(input: &Input) -> usize {
             },
         }
     }
-    // print(&grid, player);
+    trace!(Debug, "Final grid:\n{}", show(&grid, player));
     score(&grid, Cell::BlockLeft)
 }
 
@@ -277,10 +257,10 @@ fn q1(input: &Input) -> usize {
         mut player,
         mut grid,
     } = input.to_owned();
-    for dir in instructions {
-        // println!("Move #{i} {:?}", dir);
+    for (i, dir) in instructions.into_iter().enumerate() {
+        trace!(Debug, "Move #{i} {dir:?}");
         try_move(dir, &mut player, &mut grid);
-        // print(&grid, player);
+        trace!(Trace, "\n{}", show(&grid, player));
     }
     score(&grid, Cell::Block)
 }
@@ -297,31 +277,50 @@ fn gps(p: Point) -> usize {
     (100 * p.y + p.x).try_into().unwrap()
 }
 
-#[derive(Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd, GridCell)]
 enum Cell {
+    #[cell('#')]
     Wall,
+    #[cell('O')]
     Block,
+    #[cell('.')]
     Empty,
+    #[cell('[')]
     BlockLeft,
+    #[cell(']')]
     BlockRight,
 }
 
-impl std::fmt::Display for Cell {
+impl std::fmt::Debug for Cell {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let ch = match self {
-            Cell::Wall => '#',
-            Cell::Block => 'O',
-            Cell::Empty => '.',
-            Cell::BlockLeft => '[',
-            Cell::BlockRight => ']',
-        };
-        write!(f, "{ch}",)
+        write!(f, "{self}")
     }
 }
 
-impl std::fmt::Debug for Cell {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{self}")
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input = Input;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn lint(lint: &mut Linter) {
+        if let [map, _] = lint.sections(2)[..] {
+            lint.grid(map);
+            lint.marker(map, '@', "robot");
+        }
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params, _ctx: &Context) -> Answer {
+        q1(input).into()
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params, _ctx: &Context) -> Option<Answer> {
+        Some(q2(input).into())
     }
 }
 
@@ -330,52 +329,52 @@ mod tests {
     use super::*;
 
     const TEST_INPUT_SMALL: &str = "\
-    ########
-    #..O.O.#
-    ##@.O..#
-    #...O..#
-    #.#.O..#
-    #...O..#
-    #......#
-    ########
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
 
-    <^^>>>vv<v>>v<<";
+<^^>>>vv<v>>v<<";
 
     const TEST_INPUT_MEDIUM: &str = "\
-    ##########
-    #..O..O.O#
-    #......O.#
-    #.OO..O.O#
-    #..O@..O.#
-    #O#..O...#
-    #O..O..O.#
-    #.OO.O.OO#
-    #....O...#
-    ##########
-
-    <vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
-    vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
-    ><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
-    <<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
-    ^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
-    ^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
-    >^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
-    <><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
-    ^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
-    v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
+##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
 
     #[test]
     fn example_small() {
-        let input = parse(TEST_INPUT_SMALL);
-        print(&input.grid, input.player);
+        let input = parse(TEST_INPUT_SMALL).unwrap();
+        println!("{}", show(&input.grid, input.player));
         let expected = 2028;
         assert_eq!(q1(&input), expected);
     }
 
     #[test]
     fn example_medium() {
-        let input = parse(TEST_INPUT_MEDIUM);
-        print(&input.grid, input.player);
+        let input = parse(TEST_INPUT_MEDIUM).unwrap();
+        println!("{}", show(&input.grid, input.player));
         // assert_eq!(q1(&input), 10092);
         assert_eq!(q2(&input), 9021)
     }
@@ -393,13 +392,14 @@ mod tests {
 #######
 
 <vv<<^^<<^^",
-        );
+        )
+        .unwrap();
         q2(&input);
     }
 
     #[test]
     fn test_real() {
-        let input = parse(&std::fs::read_to_string("input/2024/day15.txt").unwrap());
+        let input = parse(&real_input!(2024, 15)).unwrap();
         assert_eq!(q1(&input), 1516281);
     }
 }
@@ -2,11 +2,21 @@ use aoc_runner_derive::{aoc, aoc_generator};
 use fxhash::FxHashMap as HashMap;
 use priority_queue::PriorityQueue;
 
-use crate::{dir::Dir, grid::Grid, point::Point};
+use crate::{
+    answer::Answer,
+    dir::Dir,
+    exec::Context,
+    grid::{self, Connectivity, Grid, GridCell},
+    lint::Linter,
+    parse::{ParseError, Source},
+    point::Point,
+    solution::{NoParams, Solution},
+    viz::Color,
+};
 const TURN: usize = 1000;
 const FWD: usize = 1;
 
-struct Input {
+pub struct Input {
     grid: Grid<Cell>,
     start: Point,
     end: Point,
@@ -14,17 +24,24 @@ struct Input {
 }
 
 impl Input {
-    fn start(&self) -> State {
+    /// The reindeer starts facing east.
+    pub fn start(&self) -> State {
         State {
             position: self.start,
             facing: Dir::Right,
         }
     }
+
+    pub fn end(&self) -> Point {
+        self.end
+    }
 }
 
-#[derive(Eq, PartialEq)]
+#[derive(Clone, Eq, PartialEq, GridCell)]
 enum Cell {
+    #[cell('#')]
     Wall,
+    #[cell('.')]
     Empty,
 }
 
@@ -47,47 +64,27 @@ impl PartialOrd for Priority {
 }
 
 #[aoc_generator(day16)]
-fn parse(input: &str) -> Input {
-    let width = input.lines().next().unwrap().len();
-    let height = input.lines().count();
-    let mut inner = Vec::with_capacity(width * height);
-    let mut start = Point::default();
-    let mut end = Point::default();
-    for (y, line) in input.lines().enumerate() {
-        for (x, ch) in line.trim().chars().enumerate() {
-            let p = Point::from((x, y));
-            let cell = match ch {
-                '#' => Cell::Wall,
-                '.' => Cell::Empty,
-                'S' => {
-                    start = p;
-                    Cell::Empty
-                }
-                'E' => {
-                    end = p;
-                    Cell::Empty
-                }
-                _ => unreachable!(),
-            };
-            inner.push(cell);
-        }
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let src = Source::new(16, input);
+    let markers = [('S', "start", Cell::Empty), ('E', "end", Cell::Empty)];
+    let (grid, markers) = Grid::parse_cells(&src, input, &markers)?;
+    let start = markers.require("start", "a start tile 'S'")?;
+    let end = markers.require("end", "an end tile 'E'")?;
+    let areas = grid.components(Connectivity::Four, |a, b| a == b);
+    if areas.label(start) != areas.label(end) {
+        return Err(src.error_after(input, "a path from 'S' to 'E'"));
     }
-    let grid = Grid {
-        width,
-        height,
-        inner,
-    };
     let graph = make_graph(&grid);
-    Input {
+    Ok(Input {
         grid,
         start,
         end,
         graph,
-    }
+    })
 }
 
 #[derive(Eq, PartialEq, Hash, Clone, Copy, Debug)]
-struct State {
+pub struct State {
     position: Point,
     facing: Dir,
 }
@@ -153,7 +150,9 @@ fn make_graph(grid: &Grid<Cell>) -> Graph {
     graph
 }
 
-fn dijkstra(input: &Input, start: State, end: Point) -> (State, usize) {
+/// Finds the cheapest way from `start` to `end`, returning the state
+/// the reindeer arrives in and the cost of getting there, if it can get there at all.
+pub fn dijkstra(input: &Input, start: State, end: Point) -> Option<(State, usize)> {
     // Initialize the data structures.
     let mut visited: HashMap<State, usize> = HashMap::default();
     let mut tentative = PriorityQueue::new();
@@ -171,14 +170,11 @@ fn dijkstra(input: &Input, start: State, end: Point) -> (State, usize) {
         }
 
         if solutions.len() == 4 {
-            return solutions
-                .into_iter()
-                .min_by_key(|(_state, cost)| *cost)
-                .unwrap();
+            return solutions.into_iter().min_by_key(|(_state, cost)| *cost);
         }
 
         // Check each neighbour of the current node.
-        for neighbour in input.graph.get(&curr).unwrap() {
+        for neighbour in &input.graph[&curr] {
             // Don't visit the same node twice.
             if visited.contains_key(&neighbour.next) {
                 continue;
@@ -199,47 +195,84 @@ fn dijkstra(input: &Input, start: State, end: Point) -> (State, usize) {
         // Finished with this node.
         visited.insert(curr, cost);
     }
-    panic!("Finished all tentative nodes but never found a terminal node")
+    None
 }
 
 #[aoc(day16, part2)]
-fn q2(input: &Input) -> usize {
+fn q2_default(input: &Input) -> usize {
+    q2(input, &Context::default())
+}
+
+fn q2(input: &Input, ctx: &Context) -> usize {
     let empties: Vec<_> = input
         .grid
-        .inner
         .iter()
-        .enumerate()
-        .filter_map(|(i, cell)| {
-            if matches!(cell, Cell::Empty) {
-                Some(Point {
-                    x: (i % input.grid.width) as isize,
-                    y: (i / input.grid.width) as isize,
-                })
-            } else {
-                None
-            }
-        })
+        .filter(|&(_, cell)| *cell == Cell::Empty)
+        .map(|(p, _)| p)
         .collect();
-    println!("Found {} empty spaces", empties.len());
-    let best_path_cost = dijkstra(input, input.start(), input.end).1;
-    empties
+    trace!(Info, "Found {} empty spaces", empties.len());
+    let best_path_cost = q1(input);
+    let best_tiles: Vec<_> = empties
         .into_iter()
+        .take_while(|_| !ctx.should_stop())
         .filter(|p| {
             // If this point is part of a best path?
             let p = *p;
-            let (p_state, cost_to) = dijkstra(input, input.start(), p);
+            // Tiles walled off from the start can't be.
+            let Some((p_state, cost_to)) = dijkstra(input, input.start(), p) else {
+                return false;
+            };
             if cost_to > best_path_cost {
                 return false;
             }
-            let cost_from = dijkstra(input, p_state, input.end).1;
-            cost_to + cost_from == best_path_cost
+            dijkstra(input, p_state, input.end)
+                .is_some_and(|(_, cost_from)| cost_to + cost_from == best_path_cost)
         })
-        .count()
+        .collect();
+    trace!(
+        Debug,
+        "Tiles on a best path:\n{}",
+        grid::render(&input.grid)
+            .highlight(best_tiles.iter().copied(), Color::Green)
+            .mark(input.start, 'S', Color::Red)
+            .mark(input.end, 'E', Color::Red)
+            .ansi()
+    );
+    best_tiles.len()
 }
 
 #[aoc(day16, part1)]
 fn q1(input: &Input) -> usize {
-    dijkstra(input, input.start(), input.end).1
+    dijkstra(input, input.start(), input.end)
+        .expect("parse checked there's a path from the start to the end")
+        .1
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Input = Input;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn lint(lint: &mut Linter) {
+        let grid = lint.text();
+        lint.grid(grid);
+        lint.marker(grid, 'S', "start");
+        lint.marker(grid, 'E', "end");
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params, _ctx: &Context) -> Answer {
+        q1(input).into()
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params, ctx: &Context) -> Option<Answer> {
+        Some(q2(input, ctx).into())
+    }
 }
 
 #[cfg(test)]
@@ -284,15 +317,42 @@ mod tests {
 
     #[test]
     fn test_example() {
-        let input = parse(EXAMPLE);
+        let input = parse(EXAMPLE).unwrap();
         assert_eq!(q1(&input), 7036);
-        assert_eq!(q2(&input), 45);
+        assert_eq!(q2(&input, &Context::default()), 45);
     }
 
     #[test]
     fn test_example2() {
-        let input = parse(EXAMPLE_2);
+        let input = parse(EXAMPLE_2).unwrap();
         assert_eq!(q1(&input), 11048);
-        assert_eq!(q2(&input), 64);
+        assert_eq!(q2(&input, &Context::default()), 64);
+    }
+
+    #[test]
+    fn test_no_path() {
+        let err = parse("#####\n#S#E#\n#####").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "day 16 input, line 3, column 6: expected a path from 'S' to 'E', found end of input"
+        );
+    }
+
+    #[test]
+    fn test_walled_off_tiles() {
+        // The empty tiles on the right can't be reached, so can't be on a best path.
+        let input = parse("#######\n#S.E#.#\n#######").unwrap();
+        assert_eq!(q1(&input), 2);
+        assert_eq!(q2(&input, &Context::default()), 3);
+    }
+
+    #[test]
+    fn test_grid_round_trip() {
+        let input = parse(EXAMPLE).unwrap();
+        // The start and end are on empty tiles.
+        assert_eq!(
+            input.grid.to_string(),
+            format!("{}\n", EXAMPLE.replace(['S', 'E'], "."))
+        );
     }
 }
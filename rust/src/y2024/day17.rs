@@ -0,0 +1,364 @@
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use crate::answer::Answer;
+use crate::exec::Context;
+use crate::lint::Linter;
+use crate::parse::{ParseError, Source};
+use crate::solution::{NoParams, Solution};
+
+pub struct Input {
+    pub registers: Registers,
+    pub program: Vec<u64>,
+}
+
+/// Parses the next line, which should look like `Register A: 729`.
+fn register<'a>(
+    src: &Source<'a>,
+    lines: &mut std::str::Lines<'a>,
+    pref: &'static str,
+) -> Result<u64, ParseError> {
+    let line = src.next_line(lines, src.text(), format_args!("{pref:?}"))?;
+    src.number(src.strip_prefix(line, pref)?.trim())
+}
+
+#[aoc_generator(day17)]
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let src = Source::new(17, input);
+    let mut lines = input.lines();
+    let a = register(&src, &mut lines, "Register A: ")?;
+    let b = register(&src, &mut lines, "Register B: ")?;
+    let c = register(&src, &mut lines, "Register C: ")?;
+    src.next_line(&mut lines, input, "a blank line")?;
+    let program = src.next_line(&mut lines, input, "\"Program: \"")?;
+    let program = src.strip_prefix(program, "Program: ")?;
+    let numbers: Vec<_> = program.split(',').collect();
+    if numbers.len() % 2 != 0 {
+        return Err(src.error_after(program, "an operand for the last instruction"));
+    }
+    let mut instructions = Vec::with_capacity(numbers.len());
+    for pair in numbers.chunks(2) {
+        let [opcode, operand] = [pair[0], pair[1]].map(|num| match src.number(num)? {
+            n @ 0..=7 => Ok(n),
+            _ => Err(src.error(num, "a 3-bit number")),
+        });
+        let (opcode, operand) = (opcode?, operand?);
+        if Opcode::from(opcode).has_combo_operand() && operand == 7 {
+            return Err(src.error(pair[1], "a combo operand from 0 to 6"));
+        }
+        instructions.extend([opcode, operand]);
+    }
+    Ok(Input {
+        registers: Registers {
+            a,
+            b,
+            c,
+            output: Vec::new(),
+        },
+        program: instructions,
+    })
+}
+
+#[derive(Clone, Debug)]
+pub struct Registers {
+    a: u64,
+    b: u64,
+    c: u64,
+    output: Vec<u64>,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+struct Operand(u64);
+
+impl Operand {
+    fn literal(self) -> u64 {
+        self.0
+    }
+    #[inline(always)]
+    fn combo(self, registers: &Registers) -> u64 {
+        match self.0 {
+            // Combo operands 0 through 3 represent literal values 0 through 3.
+            0..=3 => self.0,
+            4 => registers.a,
+            5 => registers.b,
+            6 => registers.c,
+            _ => unreachable!("Combo operand 7 is reserved and will not appear in valid programs."),
+        }
+    }
+}
+
+enum Effect {
+    SetIp(usize),
+}
+
+/*
+Register A: 60589763
+Register B: 0
+Register C: 0
+
+Program:
+2,4  B = A%8
+1,5  B ^= 101
+7,5  C = A / (2**B)
+1,6  B ^= 110
+4,1  B ^= C
+5,5  Out B % 8
+0,3  A /= 2**C
+3,0  Loop back to start
+*/
+
+impl Opcode {
+    fn run(&self, registers: &mut Registers, operand: Operand) -> Option<Effect> {
+        match self {
+            Opcode::Adv => {
+                // The adv instruction (opcode 0) performs division. The numerator is the value in the A register. The denominator is found by raising 2 to the power of the instruction's combo operand. (So, an operand of 2 would divide A by 4 (2^2); an operand of 5 would divide A by 2^B.) The result of the division operation is truncated to an integer and then written to the A register.
+                registers.a = divide(registers.a, operand.combo(registers));
+                trace!(Trace, "A = A / (2 ** combo({}))", operand.0);
+            }
+            Opcode::Bxl => {
+                // The bxl instruction (opcode 1) calculates the bitwise XOR of register B and the instruction's literal operand, then stores the result in register B.
+                registers.b ^= operand.literal();
+                trace!(Trace, "B ^= {:b}", operand.literal());
+            }
+            Opcode::Bst => {
+                // The bst instruction (opcode 2) calculates the value of its combo operand modulo 8 (thereby keeping only its lowest 3 bits), then writes that value to the B register.
+                registers.b = operand.combo(registers) % 8;
+                trace!(Trace, "B = combo({}) % 8", operand.0);
+            }
+            Opcode::Jnz => {
+                // The jnz instruction (opcode 3) does nothing if the A register is 0. However, if the A register is not zero, it jumps by setting the instruction pointer to the value of its literal operand; if this instruction jumps, the instruction pointer is not increased by 2 after this instruction.
+                if registers.a != 0 {
+                    let new_ip = operand.literal().try_into().unwrap();
+                    trace!(Trace, "IP = {:b}", operand.literal());
+                    return Some(Effect::SetIp(new_ip));
+                }
+                trace!(Trace, "JNZ ignored");
+            }
+            Opcode::Bxc => {
+                // The bxc instruction (opcode 4) calculates the bitwise XOR of register B and register C, then stores the result in register B. (For legacy reasons, this instruction reads an operand but ignores it.)
+                registers.b ^= registers.c;
+                trace!(Trace, "B ^= C");
+            }
+            Opcode::Out => {
+                // The out instruction (opcode 5) calculates the value of its combo operand modulo 8, then outputs that value. (If a program outputs multiple values, they are separated by commas.)
+                registers.output.push(operand.combo(registers) % 8);
+                trace!(Trace, "Out combo({}) % 8", operand.0);
+            }
+            Opcode::Bdv => {
+                // The bdv instruction (opcode 6) works exactly like the adv instruction except that the result is stored in the B register. (The numerator is still read from the A register.)
+                registers.b = divide(registers.a, operand.combo(registers));
+                trace!(Trace, "B = A / (2 ** combo({}))", operand.0);
+            }
+            Opcode::Cdv => {
+                // The cdv instruction (opcode 7) works exactly like the adv instruction except that the result is stored in the C register. (The numerator is still read from the A register.)
+                registers.c = divide(registers.a, operand.combo(registers));
+                trace!(Trace, "C = A / (2 ** combo({}))", operand.0);
+            }
+        }
+        None
+    }
+}
+
+/// `numerator / 2**power`, which is 0 once the power is too big for a `u64`.
+fn divide(numerator: u64, power: u64) -> u64 {
+    u32::try_from(power)
+        .ok()
+        .and_then(|power| numerator.checked_shr(power))
+        .unwrap_or(0)
+}
+
+/// Runs the program, returning its output.
+/// If `stop_early`, gives up (returning nothing) as soon as the output stops matching the program.
+/// Programs needn't halt, so it also gives up (returning the output so far) when `ctx` says to.
+pub fn run(mut registers: Registers, program: &[u64], stop_early: bool, ctx: &Context) -> Vec<u64> {
+    // Run the program.
+    let mut ip = 0;
+    // Jumping to an odd address can leave an opcode without an operand, which halts too.
+    while ip + 1 < program.len() {
+        if ctx.should_stop() {
+            break;
+        }
+        let opcode = Opcode::from(program[ip]);
+        let operand = Operand::from(program[ip + 1]);
+        match opcode.run(&mut registers, operand) {
+            Some(Effect::SetIp(new_ip)) => ip = new_ip,
+            None => ip += 2,
+        }
+        if stop_early && !program.starts_with(&registers.output) {
+            return Vec::new();
+        }
+    }
+    registers.output
+}
+
+#[aoc(day17, part1)]
+fn q1_default(input: &Input) -> Answer {
+    q1(input, &Context::default())
+}
+
+fn q1(input: &Input, ctx: &Context) -> Answer {
+    run(input.registers.clone(), &input.program, false, ctx).into()
+}
+
+#[aoc(day17, part2)]
+fn q2_default(input: &Input) -> u64 {
+    q2(input, &Context::default())
+}
+
+fn q2(input: &Input, ctx: &Context) -> u64 {
+    ctx.find_first(0..u64::MAX, |i| {
+        if ctx.should_stop() {
+            return true;
+        }
+        let reg = Registers {
+            a: *i,
+            ..input.registers.clone()
+        };
+        run(reg, &input.program, true, ctx) == input.program
+    })
+    .unwrap()
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Opcode {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+impl Opcode {
+    /// Whether its operand is a combo operand, where 7 isn't allowed.
+    fn has_combo_operand(self) -> bool {
+        matches!(
+            self,
+            Self::Adv | Self::Bst | Self::Out | Self::Bdv | Self::Cdv
+        )
+    }
+}
+
+impl From<u64> for Opcode {
+    fn from(value: u64) -> Self {
+        match value {
+            0 => Self::Adv,
+            1 => Self::Bxl,
+            2 => Self::Bst,
+            3 => Self::Jnz,
+            4 => Self::Bxc,
+            5 => Self::Out,
+            6 => Self::Bdv,
+            7 => Self::Cdv,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl From<u64> for Operand {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Input = Input;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn lint(lint: &mut Linter) {
+        lint.sections(2);
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params, ctx: &Context) -> Answer {
+        q1(input, ctx)
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params, ctx: &Context) -> Option<Answer> {
+        Some(q2(input, ctx).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::*;
+    use crate::exec::{Budget, Exec};
+
+    const EXAMPLE: &str = "\
+Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0";
+
+    #[test]
+    fn test_example() {
+        let input = parse(EXAMPLE).unwrap();
+        let expected = "4,6,3,5,6,3,5,2,1,0";
+        assert_eq!(q1(&input, &Context::default()).to_string(), expected);
+    }
+
+    #[test]
+    fn test_example_q2() {
+        let input = parse(
+            "Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0",
+        )
+        .unwrap();
+        let expected = 117440;
+        assert_eq!(q2(&input, &Context::default()), expected);
+    }
+
+    #[test]
+    fn test_divide_by_huge_power() {
+        // A / 2**100, then C = A / 2**100, then output C.
+        let input =
+            parse("Register A: 729\nRegister B: 100\nRegister C: 0\n\nProgram: 0,5,7,5,5,6");
+        assert_eq!(q1(&input.unwrap(), &Context::default()).to_string(), "0");
+    }
+
+    #[test]
+    fn test_never_halts() {
+        let input = parse("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 3,0").unwrap();
+        for part in [1, 2] {
+            let budget = Budget {
+                time: Some(Duration::from_millis(50)),
+                ..Budget::default()
+            };
+            let ctx = Context::new(budget, Exec::default());
+            let start = Instant::now();
+            match part {
+                1 => drop(q1(&input, &ctx)),
+                _ => drop(q2(&input, &ctx)),
+            }
+            assert!(ctx.stopped().is_some());
+            assert!(start.elapsed() < Duration::from_secs(5));
+        }
+    }
+
+    #[test]
+    fn test_invalid_programs() {
+        let registers = "Register A: 0\nRegister B: 0\nRegister C: 0\n\n";
+        let err = parse(&format!("{registers}Program: 0,1,5")).err().unwrap();
+        assert_eq!(err.expected, "an operand for the last instruction");
+        let err = parse(&format!("{registers}Program: 1,7,5,7"))
+            .err()
+            .unwrap();
+        assert_eq!(
+            (err.column, err.expected.as_str()),
+            (16, "a combo operand from 0 to 6")
+        );
+    }
+}
@@ -0,0 +1,282 @@
+use std::collections::VecDeque;
+use std::num::NonZeroUsize;
+
+use aoc_runner_derive::{aoc, aoc_generator};
+use fxhash::FxHashMap as HashMap;
+use fxhash::FxHashSet as HashSet;
+use serde::Deserialize;
+
+use crate::answer::Answer;
+use crate::dir::Dir;
+use crate::exec::Context;
+use crate::parse::{ParseError, Source};
+use crate::point::Point;
+use crate::solution::Solution;
+
+type Input = Vec<Point>;
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// The memory space is this many cells wide, and the same high.
+    pub width: NonZeroUsize,
+    /// How many bytes have fallen before part 1 looks for a path.
+    pub corrupted: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            width: NonZeroUsize::new(71).unwrap(),
+            corrupted: 1024,
+        }
+    }
+}
+
+#[aoc_generator(day18)]
+fn parse(input: &str) -> Result<Input, ParseError> {
+    let src = Source::new(18, input);
+    input
+        .lines()
+        .map(|l| {
+            let (l, r) = src.split_once(l, ",")?;
+            Ok(Point {
+                x: src.number(l)?,
+                y: src.number(r)?,
+            })
+        })
+        .collect()
+}
+
+type Graph = HashMap<Point, HashSet<Point>>;
+
+fn make_graph(width: usize, num_corrupted: usize, input: &Input) -> Graph {
+    // Build the graph
+    let mut graph: Graph = HashMap::with_capacity_and_hasher(width * width, Default::default());
+    let corrupted: HashSet<_> = input.iter().take(num_corrupted).collect();
+    for x in 0..width {
+        for y in 0..width {
+            let p = Point::from((x, y));
+            if corrupted.contains(&p) {
+                continue;
+            }
+            for dir in Dir::all() {
+                let next = p.step_to(dir);
+                if corrupted.contains(&next)
+                    || next.x < 0
+                    || next.y < 0
+                    || next.x >= width as isize
+                    || next.y >= width as isize
+                {
+                    continue;
+                }
+                graph.entry(p).or_default().insert(next);
+            }
+        }
+    }
+    graph
+}
+
+#[aoc(day18, part1)]
+fn q1_default(input: &Input) -> Result<usize, &'static str> {
+    q1(input, &Params::default()).ok_or(NO_PATH)
+}
+
+#[aoc(day18, part2)]
+fn q2_default(input: &Input) -> Result<Answer, &'static str> {
+    q2(input, &Params::default())
+        .map(Answer::from)
+        .ok_or(NEVER_CUT_OFF)
+}
+
+const NO_PATH: &str = "there's no path to the exit";
+const NEVER_CUT_OFF: &str = "no byte cuts off the exit";
+
+/// The fewest steps to the exit, if there's a way there.
+fn q1(input: &Input, params: &Params) -> Option<usize> {
+    let graph = make_graph(params.width.get(), params.corrupted, input);
+    bfs(&graph, params.width.get(), Point::default())
+}
+
+/// The first byte to cut off the exit, if any does.
+fn q2(input: &Input, params: &Params) -> Option<Point> {
+    let graph = make_graph(params.width.get(), params.corrupted, input);
+    find_last_block(params.width.get(), params.corrupted, graph, input)
+}
+
+fn find_last_block(
+    width: usize,
+    num_corrupted: usize,
+    mut graph: Graph,
+    input: &Input,
+) -> Option<Point> {
+    for &curr in &input[num_corrupted..] {
+        for dir in Dir::all() {
+            let prev = curr.step_to(dir);
+            if let Some(prev_edges) = graph.get_mut(&prev) {
+                prev_edges.remove(&curr);
+            }
+        }
+        if bfs(&graph, width, Point::default()).is_none() {
+            return Some(curr);
+        }
+    }
+    None
+}
+
+fn bfs(graph: &Graph, width: usize, start: Point) -> Option<usize> {
+    // The node we're trying to find a path to.
+    let end = Point {
+        x: (width - 1) as isize,
+        y: (width - 1) as isize,
+    };
+
+    // Queue of nodes to explore next.
+    let mut queue = VecDeque::with_capacity(graph.len());
+    queue.push_back(start);
+
+    // Cost to get to each node -- in this graph, it's the length of the shortest path to this node.
+    let mut cost = HashMap::with_capacity_and_hasher(graph.len(), Default::default());
+    cost.insert(start, 0);
+
+    // Main loop
+    while let Some(curr) = queue.pop_front() {
+        let curr_cost = *cost.get(&curr).unwrap();
+        if curr == end {
+            return Some(curr_cost);
+        }
+        // Cells with no way out aren't in the graph at all.
+        for neighbour in graph.get(&curr).into_iter().flatten() {
+            if !cost.contains_key(neighbour) {
+                cost.insert(*neighbour, curr_cost + 1);
+                queue.push_back(*neighbour);
+            }
+        }
+    }
+    None
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    type Input = Input;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn check_params(input: &Self::Input, params: &Self::Params) -> Result<(), String> {
+        if params.corrupted >= input.len() {
+            return Err(format!(
+                "corrupted is {}, but only {} bytes fall",
+                params.corrupted,
+                input.len()
+            ));
+        }
+        Ok(())
+    }
+
+    fn part1(input: &Self::Input, params: &Self::Params, ctx: &Context) -> Answer {
+        q1(input, params).map_or_else(
+            || {
+                ctx.fail(NO_PATH);
+                Answer::Int(0)
+            },
+            Answer::from,
+        )
+    }
+
+    fn part2(input: &Self::Input, params: &Self::Params, ctx: &Context) -> Option<Answer> {
+        Some(q2(input, params).map_or_else(
+            || {
+                ctx.fail(NEVER_CUT_OFF);
+                Answer::Int(0)
+            },
+            Answer::from,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{Overrides, SolveError};
+
+    #[test]
+    fn test_example() {
+        let input = parse(
+            "\
+5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0",
+        )
+        .unwrap();
+        let params = Params {
+            width: NonZeroUsize::new(7).unwrap(),
+            corrupted: 12,
+        };
+        assert_eq!(q1(&input, &params), Some(22));
+        assert_eq!(q2(&input, &params), Some(Point { x: 6, y: 1 }));
+    }
+
+    fn solve(input: &str, part: u8, params: &[&str]) -> Result<Option<Answer>, SolveError> {
+        let parsed = crate::solution::get(2024, 18)
+            .unwrap()
+            .parse(input)
+            .unwrap();
+        let overrides = Overrides::parse(params.iter().copied()).unwrap();
+        parsed.solve(part, &overrides, &Context::default())
+    }
+
+    #[test]
+    fn test_more_corrupted_than_fall() {
+        let err = solve("1,1\n2,2", 1, &["width=3", "corrupted=2"]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 18 parameters: corrupted is 2, but only 2 bytes fall"
+        );
+    }
+
+    #[test]
+    fn test_no_path() {
+        // The exit's corner is walled off from the start.
+        let err = solve("1,0\n1,1\n0,1\n2,2", 1, &["width=3", "corrupted=3"]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "no answer (there's no path to the exit) in day18 part1"
+        );
+    }
+
+    #[test]
+    fn test_never_cut_off() {
+        let err = solve("1,1\n1,0\n2,1", 2, &["width=3", "corrupted=1"]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "no answer (no byte cuts off the exit) in day18 part2"
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use aoc_runner_derive::{aoc, aoc_generator};
+use fxhash::FxHashSet as HashSet;
+
+use crate::answer::Answer;
+use crate::exec::Context;
+use crate::lint::Linter;
+use crate::parse::{ParseError, Source};
+use crate::solution::{NoParams, Solution};
+
+#[derive(Debug)]
+pub struct Input {
+    available: HashSet<String>,
+    targets: Vec<String>,
+}
+
+#[aoc_generator(day19)]
+fn parse(input: &str) -> Result<Input, ParseError> {
+    let src = Source::new(19, input);
+    let (available, targets) = src.split_once(input, "\n\n")?;
+    let available = available
+        .split(", ")
+        .map(|towel| stripes(&src, towel, "a towel's stripes"))
+        .collect::<Result<_, _>>()?;
+    let targets = targets
+        .lines()
+        .map(|design| stripes(&src, design, "a design's stripes"))
+        .collect::<Result<_, _>>()?;
+    Ok(Input { available, targets })
+}
+
+/// Checks `s` is a non-empty run of stripe colours, which `ways_to_make` relies on.
+fn stripes(src: &Source, s: &str, expected: &str) -> Result<String, ParseError> {
+    if s.is_empty() {
+        return Err(src.error(s, expected));
+    }
+    match s.find(|c| !matches!(c, 'w' | 'u' | 'b' | 'r' | 'g')) {
+        Some(i) => Err(src.error(&s[i..], "a stripe colour (w, u, b, r or g)")),
+        None => Ok(s.to_owned()),
+    }
+}
+
+fn ways_to_make(target: &str, available: &HashSet<String>) -> usize {
+    // Definition: the `n-prefix` of a string is its prefix of length `n`.
+    //
+    // How many ways can you make the n-prefix of `target`?
+    // Only 1 way to make the empty string (0-prefix).
+    let mut possible = vec![1];
+
+    // Only 1 way at most to make the 1-prefix.
+    possible.push(if available.contains(&target[..1]) {
+        1
+    } else {
+        0
+    });
+
+    for n in 2..=target.len() {
+        let n_prefix = &target[..n];
+        let mut ways_to_make = 0;
+
+        // One easy way to make this n-prefix is to use it directly from our available strings.
+        if available.contains(n_prefix) {
+            ways_to_make += 1;
+        }
+
+        // Other ways to make this n-prefix:
+        //  - Make the 1-prefix and find the remaining suffix of length (n-1) in available
+        //  - Make the 2-prefix and find the remaining suffix of length (n-2) in available
+        //  - etc etc
+        //  - Make the (n-1) prefix and find the remaining suffix of length 1 in available
+        for suffix_len in 1..n {
+            let prefix_len = n - suffix_len;
+            let suffix = &n_prefix[prefix_len..];
+            if possible[prefix_len] > 0 && available.contains(suffix) {
+                // Because there's `k` ways to make the smaller prefix,
+                // and one way to make the smaller suffix,
+                // there's also `k` ways to make this n-prefix.
+                ways_to_make += possible[prefix_len];
+            }
+        }
+        possible.push(ways_to_make);
+    }
+    possible[target.len()]
+}
+
+#[aoc(day19, part1)]
+fn q1_default(input: &Input) -> usize {
+    q1(input, &Context::default())
+}
+
+#[aoc(day19, part2)]
+fn q2_default(input: &Input) -> usize {
+    q2(input, &Context::default())
+}
+
+fn q1(input: &Input, ctx: &Context) -> usize {
+    ctx.count(&input.targets, |target| {
+        ways_to_make(target, &input.available) > 0
+    })
+}
+
+fn q2(input: &Input, ctx: &Context) -> usize {
+    ctx.map_sum(&input.targets, |target| {
+        ways_to_make(target, &input.available)
+    })
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    type Input = Input;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn lint(lint: &mut Linter) {
+        lint.sections(2);
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params, ctx: &Context) -> Answer {
+        q1(input, ctx).into()
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params, ctx: &Context) -> Option<Answer> {
+        Some(q2(input, ctx).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input = parse(
+            "r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb",
+        )
+        .unwrap();
+        assert_eq!(q1(&input, &Context::default()), 6);
+        assert_eq!(q2(&input, &Context::default()), 16);
+    }
+
+    #[test]
+    fn test_malformed() {
+        let err = parse("r, wr, b\n\n\nbrwrr").unwrap_err();
+        assert_eq!((err.line, err.expected.as_str()), (3, "a design's stripes"));
+        let err = parse("r, , b\n\nbrwrr").unwrap_err();
+        assert_eq!(
+            (err.column, err.expected.as_str()),
+            (4, "a towel's stripes")
+        );
+        let err = parse("r, wr, b\n\nbr x").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 3));
+    }
+}
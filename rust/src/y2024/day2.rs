@@ -1,18 +1,22 @@
-use std::str::FromStr;
-
 use aoc_runner_derive::{aoc, aoc_generator};
 
+use crate::answer::Answer;
+use crate::exec::Context;
+use crate::parse::{ParseError, Source};
+use crate::solution::{NoParams, Solution};
+
 type Level = i64;
 type Report = Vec<Level>;
 
 #[aoc_generator(day2)]
-fn parse_input(input: &str) -> Vec<Report> {
-    input.lines().map(parse_report).collect()
+fn parse_input(input: &str) -> Result<Vec<Report>, ParseError> {
+    let src = Source::new(2, input);
+    src.lines().map(|line| parse_report(&src, line)).collect()
 }
 
-fn parse_report(line: &str) -> Report {
+fn parse_report<'a>(src: &Source<'a>, line: &'a str) -> Result<Report, ParseError> {
     line.split_whitespace()
-        .map(|chars| Level::from_str(chars).unwrap())
+        .map(|chars| src.number(chars))
         .collect()
 }
 
@@ -66,13 +70,33 @@ fn q2(reports: &[Report]) -> usize {
         .count()
 }
 
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Input = Vec<Report>;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params, _ctx: &Context) -> Answer {
+        q1(input).into()
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params, _ctx: &Context) -> Option<Answer> {
+        Some(q2(input).into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_q1() {
-        let reports = parse_input(TEST_INPUT);
+        let reports = parse_input(TEST_INPUT).unwrap();
         let q1 = reports
             .iter()
             .filter(|report| is_report_safe(report))
@@ -82,7 +106,7 @@ mod tests {
 
     #[test]
     fn test_q2() {
-        let reports = parse_input(TEST_INPUT);
+        let reports = parse_input(TEST_INPUT).unwrap();
         let q1 = reports
             .iter()
             .filter(|report| is_report_safe_with_dampener(report))
@@ -1,11 +1,23 @@
-use crate::{dir::Dir, point::Point};
+use crate::{
+    answer::Answer,
+    dir::Dir,
+    exec::Context,
+    grid::GridCell,
+    lint::Linter,
+    parse::{ParseError, Source},
+    point::Point,
+    solution::Solution,
+};
 use aoc_runner_derive::{aoc, aoc_generator};
 use fxhash::FxHashSet as HashSet;
 use itertools::Itertools;
+use serde::Deserialize;
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, GridCell)]
 enum Cell {
+    #[cell('.')]
     Empty,
+    #[cell('#')]
     Wall,
 }
 
@@ -35,43 +47,41 @@ impl PartialEq for Cheat {
     }
 }
 
-struct Input {
+pub struct Input {
     path: Vec<Point>,
 }
 
-#[aoc_generator(day20)]
-fn parse(input: &str) -> Input {
-    // First, parse 2D grid.
-    let mut inner = Vec::new();
-    let height = input.lines().count();
-    let width = input.lines().next().unwrap().len();
-    let mut start = Point::default();
-    let mut end = Point::default();
-    for (y, line) in input.trim().lines().enumerate() {
-        for (x, ch) in line.chars().enumerate() {
-            let p = Point::from((x, y));
-            match ch {
-                '.' => {
-                    inner.push(Cell::Empty);
-                }
-                '#' => inner.push(Cell::Wall),
-                'S' => {
-                    start = p;
-                    inner.push(Cell::Empty);
-                }
-                'E' => {
-                    end = p;
-                    inner.push(Cell::Empty);
-                }
-                other => unreachable!("weird char {other}"),
-            }
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// Only count cheats which save at least this many picoseconds.
+    pub threshold: usize,
+    /// How many picoseconds a cheat can last in part 1.
+    pub part1_cheat_length: usize,
+    /// How many picoseconds a cheat can last in part 2.
+    pub part2_cheat_length: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            threshold: 100,
+            part1_cheat_length: 2,
+            part2_cheat_length: 20,
         }
     }
-    let grid = Grid {
-        width,
-        height,
-        inner,
-    };
+}
+
+#[aoc_generator(day20)]
+fn parse(input: &str) -> Result<Input, ParseError> {
+    // First, parse 2D grid.
+    let src = Source::new(20, input);
+    let input = input.trim();
+    let markers = [('S', "start", Cell::Empty), ('E', "end", Cell::Empty)];
+    let (grid, markers) = Grid::parse_cells(&src, input, &markers)?;
+    let start = markers.require("start", "a start 'S'")?;
+    let end = markers.require("end", "an end 'E'")?;
+    let (width, height) = (grid.width, grid.height);
 
     // Find the singular path through the grid.
     let mut seen = HashSet::with_capacity_and_hasher(width * height, Default::default());
@@ -82,15 +92,17 @@ fn parse(input: &str) -> Input {
         seen.insert(curr);
         for dir in Dir::all() {
             let next = curr.step_to(dir);
-            if !seen.contains(&next) && grid.get_unchecked(next) == &Cell::Empty {
+            if !seen.contains(&next) && grid.get(next) == Some(&Cell::Empty) {
                 stack.push(next);
             }
         }
     }
-    assert_eq!(&start, path.first().unwrap());
-    assert_eq!(&end, path.last().unwrap());
+    // The path starts at the start, but it only ends at the end if there's one way there.
+    if path.last() != Some(&end) {
+        return Err(src.error_after(input, "a path from 'S' to 'E'"));
+    }
 
-    Input { path }
+    Ok(Input { path })
 }
 
 impl Input {
@@ -150,19 +162,62 @@ fn cheats_over(save_at_least: usize, input: &Input, max_cheat_length: usize) ->
 }
 
 #[aoc(day20, part1)]
-fn q1(input: &Input) -> usize {
-    cheats_over(100, input, 2)
+fn q1_default(input: &Input) -> usize {
+    q1(input, &Params::default())
 }
 
 #[aoc(day20, part2)]
-fn q2(input: &Input) -> usize {
-    cheats_over(100, input, 20)
+fn q2_default(input: &Input) -> usize {
+    q2(input, &Params::default())
+}
+
+fn q1(input: &Input, params: &Params) -> usize {
+    cheats_over(params.threshold, input, params.part1_cheat_length)
+}
+
+fn q2(input: &Input, params: &Params) -> usize {
+    cheats_over(params.threshold, input, params.part2_cheat_length)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    type Input = Input;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn lint(lint: &mut Linter) {
+        let grid = lint.text();
+        lint.grid(grid);
+        lint.marker(grid, 'S', "start");
+        lint.marker(grid, 'E', "end");
+    }
+
+    fn part1(input: &Self::Input, params: &Self::Params, _ctx: &Context) -> Answer {
+        q1(input, params).into()
+    }
+
+    fn part2(input: &Self::Input, params: &Self::Params, _ctx: &Context) -> Option<Answer> {
+        Some(q2(input, params).into())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_no_path() {
+        for maze in ["#####\n#S#E#\n#####", "S.E\n..."] {
+            let err = parse(maze).err().unwrap();
+            assert_eq!(err.expected, "a path from 'S' to 'E'");
+        }
+    }
+
     #[test]
     fn test_example() {
         let input = parse(
@@ -181,11 +236,16 @@ mod tests {
 #.#.#.#.#.#.###
 #...#...#...###
 ###############",
-        );
+        )
+        .unwrap();
         assert_eq!(input.baseline_speed(), 84);
-        assert_eq!(cheats_over(39, &input, 2), 2);
-        assert_eq!(cheats_over(15, &input, 2), 5);
-        assert_eq!(cheats_over(73, &input, 20), 7);
+        let params = |threshold| Params {
+            threshold,
+            ..Params::default()
+        };
+        assert_eq!(q1(&input, &params(39)), 2);
+        assert_eq!(q1(&input, &params(15)), 5);
+        assert_eq!(q2(&input, &params(73)), 7);
     }
 
     #[test]
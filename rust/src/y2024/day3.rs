@@ -5,6 +5,11 @@ use winnow::{
     prelude::*,
 };
 
+use crate::answer::Answer;
+use crate::exec::Context;
+use crate::parse::ParseError;
+use crate::solution::{NoParams, Solution};
+
 #[aoc(day3, part1)]
 fn q1(input: &str) -> u32 {
     parse(input, Part::P1)
@@ -75,6 +80,26 @@ fn parse(input: &str, part: Part) -> u32 {
     sum_of_product
 }
 
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Input = String;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_owned())
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params, _ctx: &Context) -> Answer {
+        q1(input).into()
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params, _ctx: &Context) -> Option<Answer> {
+        Some(q2(input).into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -90,10 +115,10 @@ mod tests {
 
     #[test]
     fn test_real() {
-        let input = include_str!("../input/2024/day3.txt");
+        let input = real_input!(2024, 3);
 
-        let actual_q1 = parse(input, Part::P1);
-        let actual_q2 = parse(input, Part::P2);
+        let actual_q1 = parse(&input, Part::P1);
+        let actual_q2 = parse(&input, Part::P2);
 
         let expected_q1 = 153469856;
         let expected_q2 = 77055967;
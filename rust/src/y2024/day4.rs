@@ -0,0 +1,167 @@
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use std::convert::Infallible;
+
+use crate::answer::Answer;
+use crate::exec::Context;
+use crate::grid::Tile;
+use crate::lint::Linter;
+use crate::parse::{ParseError, Source};
+use crate::point::Point;
+use crate::solution::{NoParams, Solution};
+
+const XMAS: [char; 4] = ['X', 'M', 'A', 'S'];
+const XMAS_BACKWARDS: [char; 4] = ['S', 'A', 'M', 'X'];
+
+pub type Grid = crate::grid::Grid<char>;
+
+#[aoc_generator(day4)]
+fn parse(input: &str) -> Result<Grid, ParseError> {
+    let src = Source::new(4, input);
+    let (grid, _) = Grid::parse(&src, input, |ch| Ok::<_, Infallible>(Tile::Cell(ch)))?;
+    Ok(grid)
+}
+
+#[aoc(day4, part2)]
+fn solve_q2(grid: &Grid) -> usize {
+    grid.iter()
+        .filter(|&(_, &ch)| ch == 'A')
+        .filter(|&(a, _)| {
+            // Check for the remaining MAS letters.
+            let corner = |x, y| grid.get_copied(a + Point { x, y });
+            let quadrants = [corner(-1, -1), corner(1, -1), corner(-1, 1), corner(1, 1)];
+            [
+                ['M', 'S', 'M', 'S'],
+                ['S', 'M', 'S', 'M'],
+                ['M', 'M', 'S', 'S'],
+                ['S', 'S', 'M', 'M'],
+            ]
+            .map(|letters| letters.map(Some))
+            .contains(&quadrants)
+        })
+        .count()
+}
+
+#[aoc(day4, part1)]
+fn solve_q1(grid: &Grid) -> usize {
+    let lines = grid
+        .rows()
+        .chain(grid.columns())
+        .chain(grid.diagonals())
+        .chain(grid.anti_diagonals());
+    lines
+        .map(|line| {
+            let letters: Vec<char> = line.map(|(_, &ch)| ch).collect();
+            letters
+                .windows(4)
+                .filter(|&word| word == XMAS || word == XMAS_BACKWARDS)
+                .count()
+        })
+        .sum()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Input = Grid;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn lint(lint: &mut Linter) {
+        let grid = lint.text();
+        lint.grid(grid);
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params, _ctx: &Context) -> Answer {
+        solve_q1(input).into()
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params, _ctx: &Context) -> Option<Answer> {
+        Some(solve_q2(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+";
+
+    #[test]
+    fn test_q1() {
+        let grid = parse(TEST_INPUT).unwrap();
+        assert_eq!(grid.height, 10);
+        assert_eq!(grid.width, 10);
+        let q1 = solve_q1(&grid);
+        assert_eq!(q1, 18);
+    }
+
+    #[test]
+    fn test_q1_vertical_forwards() {
+        let grid = parse(
+            "\
+XQQQ
+MQQQ
+AQQQ
+SQQQ
+",
+        )
+        .unwrap();
+        assert_eq!(grid.height, 4);
+        assert_eq!(grid.width, 4);
+        let q1 = solve_q1(&grid);
+        assert_eq!(q1, 1);
+    }
+    #[test]
+    fn test_q1_vertical_reverse() {
+        let grid = parse(
+            "\
+SQQQ
+AQQQ
+MQQQ
+XQQQ
+",
+        )
+        .unwrap();
+        assert_eq!(grid.height, 4);
+        assert_eq!(grid.width, 4);
+        let q1 = solve_q1(&grid);
+        assert_eq!(q1, 1);
+    }
+
+    #[test]
+    fn test_q2() {
+        let grid = parse(TEST_INPUT).unwrap();
+        assert_eq!(grid.height, 10);
+        assert_eq!(grid.width, 10);
+        let q2 = solve_q2(&grid);
+        assert_eq!(q2, 9);
+    }
+    #[test]
+    fn test_q2_real() {
+        let grid = parse(&real_input!(2024, 4)).unwrap();
+        let q2 = solve_q2(&grid);
+        assert_eq!(q2, 1905);
+    }
+    #[test]
+    fn test_q1_real() {
+        let grid = parse(&real_input!(2024, 4)).unwrap();
+        let q1 = solve_q1(&grid);
+        assert_eq!(q1, 2613);
+    }
+}
@@ -2,9 +2,14 @@ use aoc_runner_derive::aoc;
 use aoc_runner_derive::aoc_generator;
 use fxhash::FxHashMap as HashMap;
 use fxhash::FxHashSet as HashSet;
-use rayon::prelude::*;
 use std::ops::Not;
 
+use crate::answer::Answer;
+use crate::exec::Context;
+use crate::lint::Linter;
+use crate::parse::{ParseError, Source};
+use crate::solution::{NoParams, Solution};
+
 /// Find the middle page from the list of all pages, after they've been
 /// ordered according to the given constraints.
 fn find_middle_page(all_pages: &HashSet<u32>, constraints: &[(u32, u32)]) -> u32 {
@@ -50,73 +55,78 @@ fn find_middle_page(all_pages: &HashSet<u32>, constraints: &[(u32, u32)]) -> u32
 type Update = Vec<u32>;
 
 #[derive(Debug)]
-struct Parsed {
+pub struct Parsed {
     constraints: Vec<(u32, u32)>,
     updates: Vec<Update>,
 }
 
 #[aoc_generator(day5)]
-fn parse(input: &str) -> Parsed {
-    let (constraints, updates) = input.split_once("\n\n").unwrap();
+fn parse(input: &str) -> Result<Parsed, ParseError> {
+    let src = Source::new(5, input);
+    let (constraints, updates) = src.split_once(input, "\n\n")?;
 
     let constraints: Vec<_> = constraints
-        .par_lines()
+        .lines()
         .map(|line| {
-            let (l, r) = line.split_once('|').unwrap();
-            (l.parse().unwrap(), r.parse().unwrap())
+            let (l, r) = src.split_once(line, "|")?;
+            Ok((src.number(l)?, src.number(r)?))
         })
-        .collect();
+        .collect::<Result<_, ParseError>>()?;
 
     let updates: Vec<_> = updates
         .lines()
-        .map(|line| line.split(',').map(|s| s.parse().unwrap()).collect())
-        .collect();
+        .map(|line| line.split(',').map(|s| src.number(s)).collect())
+        .collect::<Result<_, _>>()?;
 
-    assert!(updates.is_empty().not());
-    assert!(constraints.is_empty().not());
+    if constraints.is_empty() {
+        return Err(src.error(input, "page ordering rules"));
+    }
+    if updates.is_empty() {
+        return Err(src.error_after(input, "updates"));
+    }
 
-    Parsed {
+    Ok(Parsed {
         constraints,
         updates,
-    }
+    })
 }
 
 #[aoc(day5, part2)]
-fn solve_q2(parsed: &Parsed) -> u32 {
-    parsed
-        .updates
-        .par_iter()
-        .filter_map(|update| {
-            if parsed.update_is_correct(update) {
-                return None;
-            }
-            let numbers_in_update: HashSet<_> = update.iter().copied().collect();
-            Some(find_middle_page(
-                &numbers_in_update,
-                &parsed
-                    .constraints
-                    .iter()
-                    .filter(|(l, r)| numbers_in_update.contains(l) && numbers_in_update.contains(r))
-                    .copied()
-                    .collect::<Vec<_>>(),
-            ))
-        })
-        .sum()
+fn solve_q2_default(parsed: &Parsed) -> u32 {
+    solve_q2(parsed, &Context::default())
 }
 
 #[aoc(day5, part1)]
-fn solve_q1(parsed: &Parsed) -> u32 {
-    parsed
-        .updates
-        .par_iter()
-        .filter_map(|update| {
-            if parsed.update_is_correct(update) {
-                Some(update[update.len() / 2])
-            } else {
-                None
-            }
-        })
-        .sum()
+fn solve_q1_default(parsed: &Parsed) -> u32 {
+    solve_q1(parsed, &Context::default())
+}
+
+fn solve_q2(parsed: &Parsed, ctx: &Context) -> u32 {
+    ctx.map_sum(&parsed.updates, |update| {
+        if parsed.update_is_correct(update) {
+            return 0;
+        }
+        let numbers_in_update: HashSet<_> = update.iter().copied().collect();
+        find_middle_page(
+            &numbers_in_update,
+            &parsed
+                .constraints
+                .iter()
+                .filter(|(l, r)| numbers_in_update.contains(l) && numbers_in_update.contains(r))
+                .copied()
+                .collect::<Vec<_>>(),
+        )
+    })
+}
+
+fn solve_q1(parsed: &Parsed, ctx: &Context) -> u32 {
+    ctx.map_sum(&parsed.updates, |update| {
+        if parsed.update_is_correct(update) {
+            update[update.len() / 2]
+        } else {
+            0
+        }
+    })
 }
 
 impl Parsed {
@@ -146,6 +156,30 @@ impl Parsed {
     }
 }
 
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Input = Parsed;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn lint(lint: &mut Linter) {
+        lint.sections(2);
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params, ctx: &Context) -> Answer {
+        solve_q1(input, ctx).into()
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params, ctx: &Context) -> Option<Answer> {
+        Some(solve_q2(input, ctx).into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -179,25 +213,31 @@ mod tests {
 61,13,29
 97,13,75,29,47";
 
-    const INPUT: &str = include_str!("../input/2024/day5.txt");
-
     #[test]
     fn test_q1() {
-        let input = parse(TEST_INPUT);
+        let input = parse(TEST_INPUT).unwrap();
         assert_eq!(input.updates.len(), 6);
         assert_eq!(input.constraints.len(), 21);
 
         assert_eq!(input.updates_in_correct_order(), vec![0, 1, 2]);
-        assert_eq!(solve_q1(&input), 143);
-        let input = parse(INPUT);
-        assert_eq!(solve_q1(&input), 5955);
+        assert_eq!(solve_q1(&input, &Context::default()), 143);
+    }
+
+    #[test]
+    fn test_q1_real() {
+        let input = parse(&real_input!(2024, 5)).unwrap();
+        assert_eq!(solve_q1(&input, &Context::default()), 5955);
     }
 
     #[test]
     fn test_q2() {
-        let input = parse(TEST_INPUT);
-        assert_eq!(solve_q2(&input), 123);
-        let input = parse(INPUT);
-        assert_eq!(solve_q2(&input), 4030);
+        let input = parse(TEST_INPUT).unwrap();
+        assert_eq!(solve_q2(&input, &Context::default()), 123);
+    }
+
+    #[test]
+    fn test_q2_real() {
+        let input = parse(&real_input!(2024, 5)).unwrap();
+        assert_eq!(solve_q2(&input, &Context::default()), 4030);
     }
 }
@@ -1,13 +1,18 @@
+use crate::answer::Answer;
 use crate::dir::Dir;
+use crate::exec::Context;
+use crate::grid::Tile;
+use crate::lint::Linter;
+use crate::parse::{ParseError, Source};
 use crate::point::Point;
+use crate::solution::{NoParams, Solution};
 use aoc_runner_derive::{aoc, aoc_generator};
 use fxhash::FxHashSet as HashSet;
-use rayon::prelude::*;
 
 type Grid = crate::grid::Grid<bool>;
 
 #[derive(Clone, Copy, Eq, PartialEq, Hash)]
-struct Guard {
+pub struct Guard {
     position: Point,
     direction: Dir,
 }
@@ -21,47 +26,23 @@ impl Guard {
 
 /// Parse the puzzle input.
 #[aoc_generator(day6)]
-fn parse(input: &str) -> (Grid, Guard) {
-    let mut guard = None;
-    let two_d: Vec<_> = input
-        .lines()
-        .enumerate()
-        .map(|(y, line)| {
-            line.chars()
-                .enumerate()
-                .map(|(x, ch)| {
-                    if ch == '^' {
-                        guard = Some(Point {
-                            x: x as isize,
-                            y: y as isize,
-                        });
-                        false
-                    } else {
-                        ch == '#'
-                    }
-                })
-                .collect::<Vec<_>>()
-        })
-        .collect();
-    let height = two_d.len();
-    let width = two_d[0].len();
-    let inner = two_d.into_iter().flatten().collect();
-    let guard = guard.unwrap();
-
-    (
-        Grid {
-            inner,
-            height,
-            width,
-        },
+fn parse(input: &str) -> Result<(Grid, Guard), ParseError> {
+    let src = Source::new(6, input);
+    let (grid, markers) = Grid::parse(&src, input, |ch| match ch {
+        '^' => Ok(Tile::Marker("guard", false)),
+        '#' => Ok(Tile::Cell(true)),
+        '.' => Ok(Tile::Cell(false)),
+        _ => Err("one of '.', '#' or '^'"),
+    })?;
+    let guard = markers.require("guard", "a guard '^'")?;
+
+    Ok((
+        grid,
         Guard {
-            position: Point {
-                x: guard.x,
-                y: guard.y,
-            },
+            position: guard,
             direction: Dir::Up,
         },
-    )
+    ))
 }
 
 #[aoc(day6, part1)]
@@ -82,19 +63,20 @@ fn q1((grid, mut guard): &(Grid, Guard)) -> usize {
 }
 
 #[aoc(day6, part2)]
-fn q2((grid, guard): &(Grid, Guard)) -> usize {
+fn q2_default(input: &(Grid, Guard)) -> usize {
+    q2(input, &Context::default())
+}
+
+fn q2((grid, guard): &(Grid, Guard), ctx: &Context) -> usize {
     // Iterate over every column and row in the grid,
     // but iterate over rows *in parallel*.
     // This dramatically improves performance on multi-core machines.
-    (0..grid.width)
-        .into_par_iter()
-        .map(|x| {
-            let mut new_grid = grid.clone();
-            (0..grid.height)
-                .filter(|y| guard_loops_at(x, *y, &mut new_grid, guard))
-                .count()
-        })
-        .sum()
+    ctx.map_sum(0..grid.width, |x| {
+        let mut new_grid = grid.clone();
+        (0..grid.height)
+            .filter(|y| guard_loops_at(x, *y, &mut new_grid, guard))
+            .count()
+    })
 }
 
 /// Checks if placing an obstacle at the given (x,y) point will
@@ -136,6 +118,32 @@ fn loops(grid: &Grid, mut guard: Guard) -> bool {
     false
 }
 
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    type Input = (Grid, Guard);
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn lint(lint: &mut Linter) {
+        let grid = lint.text();
+        lint.grid(grid);
+        lint.marker(grid, '^', "guard");
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params, _ctx: &Context) -> Answer {
+        q1(input).into()
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params, ctx: &Context) -> Option<Answer> {
+        Some(q2(input, ctx).into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -154,13 +162,13 @@ mod tests {
 
     #[test]
     fn test_q1() {
-        let (grid, guard) = parse(TEST_INPUT);
+        let (grid, guard) = parse(TEST_INPUT).unwrap();
         assert_eq!(q1(&(grid, guard)), 41);
     }
 
     #[test]
     fn test_q2() {
-        let (grid, guard) = parse(TEST_INPUT);
-        assert_eq!(q2(&(grid, guard)), 6);
+        let (grid, guard) = parse(TEST_INPUT).unwrap();
+        assert_eq!(q2(&(grid, guard), &Context::default()), 6);
     }
 }
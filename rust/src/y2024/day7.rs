@@ -0,0 +1,126 @@
+use aoc_runner_derive::{aoc, aoc_generator};
+
+use crate::answer::Answer;
+use crate::exec::Context;
+use crate::parse::{ParseError, Source};
+use crate::solution::{NoParams, Solution};
+
+pub struct Equation {
+    goal: u64,
+    items: Vec<u64>,
+}
+
+fn is_solvable(goal: u64, items: &[u64], allow_concat: bool) -> bool {
+    // Base case.
+    let Some((curr, rest)) = items.split_last() else {
+        return goal == 0;
+    };
+
+    // Three recursive cases:
+    // 1. Using + operation
+    is_solvable(goal - curr, rest, allow_concat)
+    // 2. Using * operation
+    || goal.is_multiple_of(*curr) && is_solvable(goal / curr, rest, allow_concat)
+    // 3. Using || operation
+    || {
+        let new_goal = goal - curr;
+        let tens = 10u64.pow(curr.ilog10() + 1);
+        allow_concat && new_goal.is_multiple_of(tens) && is_solvable(new_goal / tens, rest, allow_concat)
+    }
+}
+
+#[aoc_generator(day7)]
+fn parse(input: &str) -> Result<Vec<Equation>, ParseError> {
+    let src = Source::new(7, input);
+    input
+        .lines()
+        .map(|line| {
+            let (goal, nums) = src.split_once(line, ": ")?;
+            let items = nums
+                .split(' ')
+                .map(|num| src.number(num))
+                .collect::<Result<_, _>>()?;
+            Ok(Equation {
+                goal: src.number(goal)?,
+                items,
+            })
+        })
+        .collect()
+}
+
+#[aoc(day7, part1, Recursive)]
+fn q1_default(input: &[Equation]) -> u64 {
+    q1(input, &Context::default())
+}
+
+#[aoc(day7, part2, Recursive)]
+fn q2_default(input: &[Equation]) -> u64 {
+    q2(input, &Context::default())
+}
+
+fn q1(input: &[Equation], ctx: &Context) -> u64 {
+    ctx.map_sum(input, |e| {
+        if is_solvable(e.goal, &e.items, false) {
+            e.goal
+        } else {
+            0
+        }
+    })
+}
+
+fn q2(input: &[Equation], ctx: &Context) -> u64 {
+    ctx.map_sum(input, |e| {
+        if is_solvable(e.goal, &e.items, true) {
+            e.goal
+        } else {
+            0
+        }
+    })
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Input = Vec<Equation>;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params, ctx: &Context) -> Answer {
+        q1(input, ctx).into()
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params, ctx: &Context) -> Option<Answer> {
+        Some(q2(input, ctx).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20";
+
+    #[test]
+    fn test_q1() {
+        let input = parse(TEST_INPUT).unwrap();
+        assert_eq!(q1(&input, &Context::default()), 3749);
+    }
+
+    #[test]
+    fn test_q2() {
+        let input = parse(TEST_INPUT).unwrap();
+        assert_eq!(q2(&input, &Context::default()), 11387);
+    }
+}
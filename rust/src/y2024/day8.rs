@@ -1,18 +1,23 @@
+use crate::answer::Answer;
+use crate::exec::Context;
+use crate::lint::Linter;
+use crate::parse::{ParseError, Source};
 use crate::point::Point;
+use crate::solution::{NoParams, Solution};
 use aoc_runner_derive::{aoc, aoc_generator};
 use fxhash::FxHashMap as HashMap;
 use fxhash::FxHashSet as HashSet;
 
-struct Input {
+pub struct Input {
     antennae_pairs: Vec<(Point, Point)>,
     width: isize,
     height: isize,
 }
 
 #[aoc_generator(day8)]
-fn parse(input: &str) -> Input {
-    let height = input.lines().count() as isize;
-    let width = input.lines().next().unwrap().len() as isize;
+fn parse(input: &str) -> Result<Input, ParseError> {
+    let (width, height) = Source::new(8, input).rectangle(input)?;
+    let (width, height) = (width as isize, height as isize);
 
     // Key = frequency (a single character).
     // Value = list of all antennae locations broadcasting on that frequency.
@@ -58,11 +63,11 @@ fn parse(input: &str) -> Input {
         })
         .collect();
 
-    Input {
+    Ok(Input {
         antennae_pairs,
         height,
         width,
-    }
+    })
 }
 
 #[aoc(day8, part1)]
@@ -120,6 +125,31 @@ fn q2(input: &Input) -> usize {
     places_with_antinodes.len()
 }
 
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Input = Input;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn lint(lint: &mut Linter) {
+        let grid = lint.text();
+        lint.grid(grid);
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params, _ctx: &Context) -> Answer {
+        q1(input).into()
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params, _ctx: &Context) -> Option<Answer> {
+        Some(q2(input).into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -139,7 +169,8 @@ mod tests {
 .........A..
 ............
 ............",
-        );
+        )
+        .unwrap();
         assert_eq!(q1(&input), 14);
     }
 
@@ -158,7 +189,8 @@ mod tests {
 .........A..
 ............
 ............",
-        );
+        )
+        .unwrap();
         assert_eq!(q2(&input), 34);
     }
 }
@@ -2,11 +2,16 @@ use std::iter;
 
 use aoc_runner_derive::{aoc, aoc_generator};
 
+use crate::answer::Answer;
+use crate::exec::Context;
+use crate::parse::{ParseError, Source};
+use crate::solution::{NoParams, Solution};
+
 type Input = DiskMap;
 type FileId = u32;
 
 #[derive(Eq, PartialEq, Clone)]
-struct DiskMap {
+pub struct DiskMap {
     disk: Vec<Option<FileId>>,
 }
 
@@ -123,13 +128,17 @@ impl DiskMap {
 }
 
 #[aoc_generator(day9)]
-fn parse(input: &str) -> Input {
+fn parse(input: &str) -> Result<Input, ParseError> {
+    let src = Source::new(9, input);
     let mut disk: Vec<Option<FileId>> = Vec::new();
     let mut is_file = false;
     let mut file_id = 0;
-    for ch in input.trim().chars() {
+    let input = input.trim();
+    for (i, ch) in input.char_indices() {
         is_file = !is_file;
-        let num_blocks = ch.to_digit(10).unwrap();
+        let num_blocks = ch
+            .to_digit(10)
+            .ok_or_else(|| src.error(&input[i..], "a digit"))?;
         let blocks = if is_file {
             let b = Some(file_id);
             file_id += 1;
@@ -139,7 +148,7 @@ fn parse(input: &str) -> Input {
         };
         disk.extend(iter::repeat_n(blocks, num_blocks as usize));
     }
-    DiskMap { disk }
+    Ok(DiskMap { disk })
 }
 
 #[aoc(day9, part1)]
@@ -156,6 +165,26 @@ fn q2(input: &Input) -> u64 {
     solved.checksum()
 }
 
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Input = Input;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params, _ctx: &Context) -> Answer {
+        q1(input).into()
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params, _ctx: &Context) -> Option<Answer> {
+        Some(q2(input).into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -169,7 +198,7 @@ mod tests {
                 "00...111...2...333.44.5555.6666.777.888899",
             ),
         ] {
-            let mut actual = parse(input);
+            let mut actual = parse(input).unwrap();
             assert_eq!(format!("{actual:?}"), expected);
 
             println!("Fragmentd: {actual:?}");
@@ -180,7 +209,7 @@ mod tests {
 
     #[test]
     fn test_real() {
-        let mut disk = parse(&std::fs::read_to_string("input/2024/day9.txt").unwrap());
+        let mut disk = parse(&real_input!(2024, 9)).unwrap();
         disk.defrag();
         let expected = 6241633730082;
         assert_eq!(q1(&disk), expected);
@@ -188,7 +217,7 @@ mod tests {
 
     #[test]
     fn test_example() {
-        let disk = parse("2333133121414131402");
+        let disk = parse("2333133121414131402").unwrap();
         let actual = q1(&disk);
         let expected = 1928;
         assert_eq!(actual, expected);
@@ -196,14 +225,14 @@ mod tests {
 
     #[test]
     fn test_example_q2() {
-        let disk = parse("2333133121414131402");
+        let disk = parse("2333133121414131402").unwrap();
         let actual = q2(&disk);
         let expected = 2858;
         assert_eq!(actual, expected);
     }
     #[test]
     fn test_real_q2() {
-        let disk = parse(&std::fs::read_to_string("input/2024/day9.txt").unwrap());
+        let disk = parse(&real_input!(2024, 9)).unwrap();
         let actual = q2(&disk);
         let expected = 6265268809555;
         assert_eq!(actual, expected);